@@ -0,0 +1,202 @@
+use std::fs;
+use std::io;
+use std::io::prelude::*;
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey
+}
+
+impl Colour {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::Grey => 90,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Glyph {
+    pub symbol: char,
+    pub colour: Option<Colour>,
+    pub active: bool
+}
+
+impl Glyph {
+    pub fn new(symbol: char, colour: Option<Colour>) -> Glyph {
+        Glyph { symbol, colour, active: false }
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Line {
+    pub glyphs: Vec<Glyph>,
+    // Shown to the right of the grid, e.g. hit points
+    pub notes: Vec<(String, Option<Colour>)>
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Frame {
+    pub caption: String,
+    pub lines: Vec<Line>
+}
+
+fn paint(text: &str, colour: Option<Colour>, active: bool, changed: bool) -> String {
+    let mut codes: Vec<String> = Vec::new();
+    if let Some(colour) = colour {
+        codes.push(colour.ansi_code().to_string());
+    }
+    if changed {
+        codes.push("1".to_string());
+        codes.push("4".to_string());
+    }
+    if active {
+        codes.push("7".to_string());
+    }
+
+    if codes.is_empty() {
+        text.to_string()
+    } else {
+        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+    }
+}
+
+impl Frame {
+    fn symbol_at(&self, x: usize, y: usize) -> Option<char> {
+        self.lines.get(y).and_then(|line| line.glyphs.get(x)).map(|glyph| glyph.symbol)
+    }
+
+    // Squares whose symbol differs from `previous` are drawn bold and underlined, the active
+    // square in reverse video
+    pub fn render_ansi(&self, previous: Option<&Frame>) -> String {
+        let mut out = format!("{}\n", self.caption);
+        for (y, line) in self.lines.iter().enumerate() {
+            for (x, glyph) in line.glyphs.iter().enumerate() {
+                let changed = previous.is_some_and(|frame| frame.symbol_at(x, y) != Some(glyph.symbol));
+                out.push_str(&paint(&glyph.symbol.to_string(), glyph.colour, glyph.active, changed));
+            }
+            if !line.notes.is_empty() {
+                out.push_str("   ");
+                let notes: Vec<String> = line.notes.iter().map(|(text, colour)| paint(text, *colour, false, false)).collect();
+                out.push_str(&notes.join(", "));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn render_plain(&self) -> String {
+        let mut out = format!("{}\n", self.caption);
+        for line in &self.lines {
+            out.extend(line.glyphs.iter().map(|glyph| glyph.symbol));
+            if !line.notes.is_empty() {
+                out.push_str("   ");
+                out.push_str(&line.notes.iter().map(|(text, _)| text.clone()).collect::<Vec<_>>().join(", "));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    Terminal { frames_per_second: f64 },
+    Directory(PathBuf)
+}
+
+pub struct Animator {
+    target: Target,
+    frames_shown: usize,
+    previous: Option<Frame>
+}
+
+impl Animator {
+    pub fn new(target: Target) -> io::Result<Animator> {
+        if let Target::Directory(ref dir) = target {
+            fs::create_dir_all(dir)?;
+        }
+
+        Ok(Animator { target, frames_shown: 0, previous: None })
+    }
+
+    pub fn frames_shown(&self) -> usize {
+        self.frames_shown
+    }
+
+    pub fn show(&mut self, frame: Frame) -> io::Result<()> {
+        match self.target {
+            Target::Terminal { frames_per_second } => {
+                let mut stdout = io::stdout();
+                // Clear the screen and move the cursor to the top left before drawing
+                write!(stdout, "\x1b[2J\x1b[H{}", frame.render_ansi(self.previous.as_ref()))?;
+                stdout.flush()?;
+                if frames_per_second > 0.0 {
+                    thread::sleep(Duration::from_secs_f64(1.0 / frames_per_second));
+                }
+            },
+            Target::Directory(ref dir) => {
+                fs::write(dir.join(format!("{:05}.txt", self.frames_shown)), frame.render_plain())?;
+            },
+        }
+
+        self.frames_shown += 1;
+        self.previous = Some(frame);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(symbols: &str) -> Frame {
+        Frame {
+            caption: "Test".to_string(),
+            lines: vec![Line {
+                glyphs: symbols.chars().map(|c| Glyph::new(c, None)).collect(),
+                notes: vec![("E(200)".to_string(), Some(Colour::Green))]
+            }]
+        }
+    }
+
+    #[test]
+    fn render_tests() {
+        let before = frame("#.E#");
+        let mut after = frame("#E.#");
+        after.lines[0].glyphs[1].active = true;
+
+        assert_eq!(after.render_plain(), "Test\n#E.#   E(200)\n");
+        assert_eq!(
+            after.render_ansi(Some(&before)),
+            "Test\n#\x1b[1;4;7mE\x1b[0m\x1b[1;4m.\x1b[0m#   \x1b[32mE(200)\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn directory_target_numbers_frames() {
+        let dir = ::std::env::temp_dir().join(format!("aoc_animation_test_{}", ::std::process::id()));
+        let mut animator = Animator::new(Target::Directory(dir.clone())).unwrap();
+        animator.show(frame("#.")).unwrap();
+        animator.show(frame(".#")).unwrap();
+
+        assert_eq!(animator.frames_shown(), 2);
+        assert_eq!(fs::read_to_string(dir.join("00001.txt")).unwrap(), "Test\n.#   E(200)\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,92 @@
+use std::error::Error;
+use std::result;
+
+use super::disjoint_set::DisjointSet;
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+pub type Point = Vec<i64>;
+
+// One point per non-blank line, as `dimension` comma-separated integers
+pub fn parse_points(text: &str, dimension: usize) -> Result<Vec<Point>> {
+    let mut points: Vec<Point> = Vec::new();
+    for (line_idx, line) in text.lines().map(|l| l.trim()).enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let point: Point = match line.split(',').map(|c| c.trim().parse()).collect() {
+            Ok(point) => point,
+            Err(e) => return err!("Line {}: cannot parse {:?}: {}", line_idx + 1, line, e),
+        };
+        if point.len() != dimension {
+            return err!("Line {}: expected {} coordinates but found {}", line_idx + 1, dimension, point.len());
+        }
+        points.push(point);
+    }
+    Ok(points)
+}
+
+pub fn manhattan_distance(a: &[i64], b: &[i64]) -> u64 {
+    a.iter().zip(b).map(|(x, y)| (x - y).unsigned_abs()).sum()
+}
+
+// Joins points linked by chains of steps no longer than `threshold` into one set each
+fn link_points(points: &[Point], threshold: u64) -> DisjointSet {
+    // Once sorted along the first axis, only points whose first coordinates are close enough need
+    // comparing
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by_key(|&idx| points[idx].first().cloned());
+
+    let mut sets = DisjointSet::new(points.len());
+    for (i, &a) in order.iter().enumerate() {
+        for &b in &order[i + 1..] {
+            if let (Some(a_first), Some(b_first)) = (points[a].first(), points[b].first()) {
+                if (b_first - a_first).unsigned_abs() > threshold {
+                    break;
+                }
+            }
+            if !sets.same_set(a, b) && manhattan_distance(&points[a], &points[b]) <= threshold {
+                sets.union(a, b);
+            }
+        }
+    }
+    sets
+}
+
+// Groups points linked by chains of steps no longer than `threshold`. Clusters hold indices into
+// `points` and are ordered by their first point.
+#[allow(dead_code)]
+pub fn manhattan_clusters(points: &[Point], threshold: u64) -> Vec<Vec<usize>> {
+    link_points(points, threshold).sets()
+}
+
+pub fn count_manhattan_clusters(points: &[Point], threshold: u64) -> usize {
+    link_points(points, threshold).set_count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cluster_tests() {
+        let points = parse_points("0\n2\n5\n9\n11\n-3", 1).unwrap();
+        assert_eq!(manhattan_clusters(&points, 3), vec![vec![0, 1, 2, 5], vec![3, 4]]);
+        assert_eq!(count_manhattan_clusters(&points, 2), 4);
+
+        let points = parse_points("0,0\n1,1\n3,0\n0,3", 2).unwrap();
+        assert_eq!(count_manhattan_clusters(&points, 2), 3);
+    }
+
+    #[test]
+    fn parse_tests() {
+        assert_eq!(parse_points(" 1, -2,3\n\n4,5,6\n", 3).unwrap(), vec![vec![1, -2, 3], vec![4, 5, 6]]);
+        assert!(parse_points("1,2\n3", 2).is_err());
+        assert!(parse_points("1,x", 2).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// The state after `start` steps is the first one that recurs, and it recurs every `period` steps
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize
+}
+
+impl Cycle {
+    // The earliest step with the same state as after `steps` steps
+    pub fn equivalent_step(&self, steps: u64) -> usize {
+        if steps < self.start as u64 {
+            return steps as usize;
+        }
+        self.start + ((steps - self.start as u64) % self.period as u64) as usize
+    }
+}
+
+pub struct History<T> {
+    // `states[i]` is the state after `i` steps
+    pub states: Vec<T>,
+    pub cycle: Option<Cycle>
+}
+
+impl<T> History<T> {
+    pub fn state_after(&self, steps: u64) -> Option<&T> {
+        match self.cycle {
+            Some(cycle) => self.states.get(cycle.equivalent_step(steps)),
+            None if steps < self.states.len() as u64 => self.states.get(steps as usize),
+            None => None,
+        }
+    }
+}
+
+// Applies `step` from `initial` until a state repeats or `max_steps` steps have been taken
+pub fn simulate_until_repeat<T, F>(initial: T, max_steps: u64, mut step: F) -> History<T>
+    where T: Clone + Eq + Hash,
+          F: FnMut(&T) -> T
+{
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut states: Vec<T> = Vec::new();
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let period = states.len() - start;
+            return History { states, cycle: Some(Cycle { start, period }) };
+        }
+        if states.len() as u64 == max_steps {
+            states.push(state);
+            return History { states, cycle: None };
+        }
+
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+// The state after `steps` steps, jumping ahead once the simulation starts repeating
+pub fn state_after<T, F>(initial: T, steps: u64, step: F) -> T
+    where T: Clone + Eq + Hash,
+          F: FnMut(&T) -> T
+{
+    let history = simulate_until_repeat(initial, steps, step);
+    history.state_after(steps).cloned().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 1009
+    }
+
+    #[test]
+    fn finds_start_and_period() {
+        let history = simulate_until_repeat(2, u64::MAX, step);
+        let cycle = history.cycle.unwrap();
+
+        let mut states = vec![2];
+        for _ in 0..cycle.start + cycle.period {
+            let next = step(states.last().unwrap());
+            states.push(next);
+        }
+        assert_eq!(states[cycle.start], states[cycle.start + cycle.period]);
+        assert!((0..cycle.start + cycle.period).all(|i| (i + 1..cycle.start + cycle.period).all(|j| states[i] != states[j])));
+    }
+
+    #[test]
+    fn extrapolation_matches_simulation() {
+        let mut x = 2;
+        for steps in 0..5000 {
+            assert_eq!(state_after(2, steps, step), x);
+            x = step(&x);
+        }
+    }
+
+    #[test]
+    fn stops_at_max_steps() {
+        let history = simulate_until_repeat(0u64, 10, |x| x + 1);
+        assert_eq!(history.cycle, None);
+        assert_eq!(history.state_after(10), Some(&10));
+        assert_eq!(history.state_after(11), None);
+    }
+}
@@ -0,0 +1,100 @@
+// Union-find over the elements `0..len`, with path halving and union by size
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    set_count: usize
+}
+
+impl DisjointSet {
+    pub fn new(len: usize) -> DisjointSet {
+        DisjointSet {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            set_count: len
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    // The representative element of the set holding `element`
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut element = element;
+        while self.parents[element] != element {
+            self.parents[element] = self.parents[self.parents[element]];
+            element = self.parents[element];
+        }
+        element
+    }
+
+    // Merges the sets holding `a` and `b`, returning false if they were already the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            ::std::mem::swap(&mut a, &mut b);
+        }
+
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.set_count -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // Every set, each listed in increasing order, ordered by their smallest elements
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut set_of_root: Vec<Option<usize>> = vec![None; self.len()];
+        let mut sets: Vec<Vec<usize>> = Vec::new();
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let set_idx = match set_of_root[root] {
+                Some(set_idx) => set_idx,
+                None => {
+                    sets.push(Vec::new());
+                    set_of_root[root] = Some(sets.len() - 1);
+                    sets.len() - 1
+                },
+            };
+            sets[set_idx].push(element);
+        }
+        sets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_find_tests() {
+        let mut sets = DisjointSet::new(6);
+        assert_eq!(sets.set_count(), 6);
+
+        assert!(sets.union(0, 3));
+        assert!(sets.union(4, 3));
+        assert!(!sets.union(0, 4));
+        assert!(sets.union(1, 5));
+
+        assert!(sets.same_set(4, 0));
+        assert!(!sets.same_set(1, 3));
+        assert_eq!(sets.set_count(), 3);
+        assert_eq!(sets.sets(), vec![vec![0, 3, 4], vec![1, 5], vec![2]]);
+    }
+}
@@ -1,74 +1,158 @@
-#[allow(dead_code)]
-pub mod day_1;
-
-#[allow(dead_code)]
-pub mod day_2;
-
-#[allow(dead_code)]
-pub mod day_3;
-
-#[allow(dead_code)]
-pub mod day_4;
-
-#[allow(dead_code)]
-pub mod day_5;
-
-#[allow(dead_code)]
-pub mod day_6;
-
-#[allow(dead_code)]
-pub mod day_7;
-
-#[allow(dead_code)]
-pub mod day_8;
-
-#[allow(dead_code)]
-pub mod day_9;
-
-#[allow(dead_code)]
-pub mod day_10;
-
-#[allow(dead_code)]
-pub mod day_11;
-
-#[allow(dead_code)]
-pub mod day_12;
-
-#[allow(dead_code)]
-pub mod day_13;
-
-#[allow(dead_code)]
-pub mod day_14;
-
-#[allow(dead_code)]
-pub mod day_15;
-
-#[allow(dead_code)]
-pub mod day_16;
-
-#[allow(dead_code)]
-pub mod day_17;
-
-#[allow(dead_code)]
-pub mod day_18;
-
-#[allow(dead_code)]
-pub mod day_19;
-
-#[allow(dead_code)]
-pub mod day_20;
-
-#[allow(dead_code)]
-pub mod day_21;
-
-#[allow(dead_code)]
-pub mod day_22;
-
-#[allow(dead_code)]
-pub mod day_23;
-
-#[allow(dead_code)]
-pub mod day_02;
-
-#[allow(dead_code)]
-pub mod day_03;
+pub mod animation;
+pub mod clustering;
+pub mod cycle;
+pub mod disjoint_set;
+pub mod y2018;
+pub mod y2019;
+
+// A solution that reads its puzzle input from a file, with the answer formatted for printing
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub run: Run
+}
+
+// The files a solution reads: the day's input, and for some parts a second input saved for that
+// part alone
+#[derive(Clone, Copy)]
+pub enum Run {
+    Input(fn(String) -> String),
+    WithPartInput(fn(String, String) -> String)
+}
+
+macro_rules! solution {
+    ($year:expr, $day:expr, $part:expr, $q:path) => {
+        Solution { year: $year, day: $day, part: $part, run: Run::Input(|fname| format!("{:?}", $q(fname))) }
+    };
+    ($year:expr, $day:expr, $part:expr, $q:path, with_part_input) => {
+        Solution {
+            year: $year,
+            day: $day,
+            part: $part,
+            run: Run::WithPartInput(|fname, part_fname| format!("{:?}", $q(fname, part_fname)))
+        }
+    };
+}
+
+// Days 11 and 14 of 2018 have no saved puzzle input, so they aren't listed
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2018, 1, 1, y2018::day01::q1),
+        solution!(2018, 1, 2, y2018::day01::q2),
+        solution!(2018, 2, 1, y2018::day02::q1),
+        solution!(2018, 2, 2, y2018::day02::q2),
+        solution!(2018, 3, 1, y2018::day03::q1),
+        solution!(2018, 3, 2, y2018::day03::q2),
+        solution!(2018, 4, 1, y2018::day04::q1),
+        solution!(2018, 4, 2, y2018::day04::q2),
+        solution!(2018, 5, 1, y2018::day05::q1),
+        solution!(2018, 5, 2, y2018::day05::q2),
+        solution!(2018, 6, 1, y2018::day06::q1),
+        solution!(2018, 6, 2, y2018::day06::q2),
+        solution!(2018, 7, 1, y2018::day07::q1),
+        solution!(2018, 7, 2, y2018::day07::q2),
+        solution!(2018, 8, 1, y2018::day08::q1),
+        solution!(2018, 8, 2, y2018::day08::q2),
+        solution!(2018, 9, 1, y2018::day09::q1),
+        solution!(2018, 10, 1, y2018::day10::q1),
+        solution!(2018, 10, 2, y2018::day10::q2),
+        solution!(2018, 12, 1, y2018::day12::q1),
+        solution!(2018, 12, 2, y2018::day12::q2),
+        solution!(2018, 13, 1, y2018::day13::q1),
+        solution!(2018, 13, 2, y2018::day13::q2),
+        solution!(2018, 15, 1, y2018::day15::q1),
+        solution!(2018, 15, 2, y2018::day15::q2),
+        solution!(2018, 16, 1, y2018::day16::q1),
+        solution!(2018, 16, 2, y2018::day16::q2, with_part_input),
+        solution!(2018, 17, 1, y2018::day17::q1),
+        solution!(2018, 17, 2, y2018::day17::q2),
+        solution!(2018, 18, 1, y2018::day18::q1),
+        solution!(2018, 18, 2, y2018::day18::q2),
+        solution!(2018, 19, 1, y2018::day19::q1),
+        solution!(2018, 20, 1, y2018::day20::q1),
+        solution!(2018, 20, 2, y2018::day20::q2),
+        solution!(2018, 21, 1, y2018::day21::q1),
+        solution!(2018, 21, 2, y2018::day21::q2),
+        solution!(2018, 22, 1, y2018::day22::q1),
+        solution!(2018, 22, 2, y2018::day22::q2),
+        solution!(2018, 23, 1, y2018::day23::q1),
+        solution!(2018, 23, 2, y2018::day23::q2),
+        solution!(2018, 24, 1, y2018::day24::q1),
+        solution!(2018, 24, 2, y2018::day24::q2),
+        solution!(2018, 25, 1, y2018::day25::q1),
+        solution!(2019, 2, 1, y2019::day02::q1),
+        solution!(2019, 2, 2, y2019::day02::q2),
+        solution!(2019, 3, 1, y2019::day03::q1),
+        solution!(2019, 3, 2, y2019::day03::q2),
+    ]
+}
+
+pub fn find_solution(year: u32, day: u32, part: u32) -> Option<Solution> {
+    solutions().into_iter().find(|s| (s.year, s.day, s.part) == (year, day, part))
+}
+
+// A solution that can be watched, showing a frame at each step
+#[derive(Clone, Copy)]
+pub struct Animation {
+    pub year: u32,
+    pub day: u32,
+    // Options of its own, each taking a value, that are passed on as (option, value) pairs
+    pub options: &'static [&'static str],
+    pub run: fn(String, animation::Target, &[(String, String)]) -> String
+}
+
+macro_rules! animation {
+    ($year:expr, $day:expr, $animate:path) => {
+        Animation { year: $year, day: $day, options: &[], run: |fname, target, _| format!("{:?}", $animate(fname, target)) }
+    };
+    ($year:expr, $day:expr, $animate:path, $options:expr) => {
+        Animation {
+            year: $year,
+            day: $day,
+            options: $options,
+            run: |fname, target, options| format!("{:?}", $animate(fname, target, options))
+        }
+    };
+}
+
+pub fn animations() -> Vec<Animation> {
+    vec![
+        animation!(2018, 15, y2018::day15::animate, &["--initiative", "--movement"]),
+        animation!(2018, 17, y2018::day17::animate),
+        animation!(2018, 22, y2018::day22::animate),
+    ]
+}
+
+pub fn find_animation(year: u32, day: u32) -> Option<Animation> {
+    animations().into_iter().find(|a| (a.year, a.day) == (year, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use inputs::InputResolver;
+    use std::path::Path;
+
+    #[test]
+    fn every_solution_is_unique_and_has_an_input() {
+        let resolver = InputResolver::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"));
+        let solutions = solutions();
+        for (idx, solution) in solutions.iter().enumerate() {
+            let key = (solution.year, solution.day, solution.part);
+            assert!(solutions[..idx].iter().all(|s| (s.year, s.day, s.part) != key), "{:?} is listed twice", key);
+            resolver.resolve(solution.year, solution.day).unwrap();
+            if let Run::WithPartInput(_) = solution.run {
+                resolver.resolve_part(solution.year, solution.day, solution.part).unwrap();
+            }
+        }
+
+        for animation in animations() {
+            assert!(solutions.iter().any(|s| (s.year, s.day) == (animation.year, animation.day)));
+        }
+
+        assert_eq!(find_solution(2019, 2, 1).map(|s| s.year), Some(2019));
+        assert!(find_solution(2018, 11, 1).is_none());
+    }
+}
@@ -3,24 +3,6 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::prelude::*;
 
-extern crate chrono;
-use self::chrono::prelude::*;
-
-#[derive(Debug)]
-struct GuardEvent {
-    dt: DateTime<Utc>,
-    event: String
-}
-
-impl GuardEvent {
-    fn new(dt_str: String, event: String) -> GuardEvent {
-        GuardEvent {
-            dt: Utc.datetime_from_str(&dt_str, "%Y-%m-%d %H:%M").unwrap(),
-            event
-        }
-    }
-}
-
 fn is_relevant_pair(c1: char, c2: char) -> bool {
     (c1.to_lowercase().to_string() == c2.to_lowercase().to_string())
         && (c1.is_lowercase() ^ c2.is_lowercase())
@@ -1,7 +1,37 @@
 use std::collections::VecDeque;
+use std::error::Error;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
 
+use regex::Regex;
 
-pub fn q1(player_num: u32, last_marble_pt: u32) -> u32 {
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+// The number of players and the last marble's points, from a line like
+// `455 players; last marble is worth 71223 points`
+fn parse_game(s: &str) -> Result<(u32, u32)> {
+    lazy_static! {
+        static ref GAME_RE: Regex = Regex::new(r"^([0-9]+) players; last marble is worth ([0-9]+) points$").unwrap();
+    }
+
+    let caps = match GAME_RE.captures(s.trim()) {
+        Some(caps) => caps,
+        None => return err!("Cannot parse game: {}", s.trim()),
+    };
+    let player_num: u32 = caps[1].parse()?;
+    if player_num == 0 {
+        return err!("A game needs at least one player");
+    }
+
+    Ok((player_num, caps[2].parse()?))
+}
+
+fn high_score(player_num: u32, last_marble_pt: u32) -> u32 {
     // positive is clockwise!!
     let mut marble_circle: VecDeque<u32> = VecDeque::new();
     marble_circle.push_back(0);
@@ -56,6 +86,20 @@ pub fn q1(player_num: u32, last_marble_pt: u32) -> u32 {
     *elf_scores.iter().max().unwrap()
 }
 
+pub fn q1(fname: String) -> u32 {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+
+    _q1(&f_contents).unwrap()
+}
+
+fn _q1(game: &str) -> Result<u32> {
+    let (player_num, last_marble_pt) = parse_game(game)?;
+
+    Ok(high_score(player_num, last_marble_pt))
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -63,7 +107,16 @@ mod tests {
 
     #[test]
     fn test_examples() {
-        assert_eq!(q1(9, 25), 32);
-        assert_eq!(q1(10, 1618), 8317);
+        assert_eq!(high_score(9, 25), 32);
+        assert_eq!(high_score(10, 1618), 8317);
+        assert_eq!(_q1("10 players; last marble is worth 1618 points\n").unwrap(), 8317);
+        assert_eq!(_q1("30 players; last marble is worth 5807 points").unwrap(), 37305);
+    }
+
+    #[test]
+    fn parse_game_tests() {
+        assert_eq!(parse_game("455 players; last marble is worth 71223 points").unwrap(), (455, 71223));
+        assert!(parse_game("455 players").is_err());
+        assert!(parse_game("0 players; last marble is worth 25 points").is_err());
     }
 }
@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+
+use regex::Regex;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+const GLYPH_WIDTH: usize = 6;
+const GLYPH_HEIGHT: usize = 10;
+
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+#[derive(Debug)]
+struct Star {
+    x: i64,
+    y: i64,
+    x_vel: i64,
+    y_vel: i64
+}
+
+impl Star {
+    fn new(x: i64, y: i64, x_vel: i64, y_vel: i64) -> Star {
+        Star {
+            x, y, x_vel, y_vel
+        }
+    }
+
+    fn position_at(&self, t: i64) -> (i64, i64) {
+        (self.x + t * self.x_vel, self.y + t * self.y_vel)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bounds {
+    left: i64,
+    right: i64,
+    top: i64,
+    bottom: i64
+}
+
+impl Bounds {
+    fn of(points: &HashSet<(i64, i64)>) -> Bounds {
+        Bounds {
+            left: points.iter().map(|&(x, _)| x).min().unwrap_or(0),
+            right: points.iter().map(|&(x, _)| x).max().unwrap_or(0),
+            top: points.iter().map(|&(_, y)| y).min().unwrap_or(0),
+            bottom: points.iter().map(|&(_, y)| y).max().unwrap_or(0),
+        }
+    }
+}
+
+fn star_positions(star_list: &[Star], t: i64) -> HashSet<(i64, i64)> {
+    star_list.iter().map(|star| star.position_at(t)).collect()
+}
+
+// Width plus height of the bounding box. Each side is a max of linear functions of t minus a min
+// of linear functions of t, so the spread is convex in t and only flattens out at its minimum.
+fn spread_at(star_list: &[Star], t: i64) -> i64 {
+    let bounds = Bounds::of(&star_positions(star_list, t));
+    (bounds.right - bounds.left) + (bounds.bottom - bounds.top)
+}
+
+fn message_time(star_list: &[Star]) -> i64 {
+    let still_shrinking = |t: i64| spread_at(star_list, t + 1) < spread_at(star_list, t);
+
+    let mut upper: i64 = 1;
+    while still_shrinking(upper) {
+        upper *= 2;
+    }
+
+    let mut lower: i64 = 0;
+    while lower < upper {
+        let mid = (lower + upper) / 2;
+        if still_shrinking(mid) {
+            lower = mid + 1;
+        } else {
+            upper = mid;
+        }
+    }
+
+    lower
+}
+
+fn star_grid(pt_set: &HashSet<(i64, i64)>) -> Vec<Vec<bool>> {
+    let bounds = Bounds::of(pt_set);
+    (bounds.top..=bounds.bottom).map(|y| {
+        (bounds.left..=bounds.right).map(|x| pt_set.contains(&(x, y))).collect()
+    }).collect()
+}
+
+fn read_glyph(grid: &[Vec<bool>], first_col: usize) -> Result<char> {
+    let glyph: Vec<String> = grid.iter().map(|row| {
+        (first_col..first_col + GLYPH_WIDTH)
+            .map(|x| if *row.get(x).unwrap_or(&false) { '#' } else { '.' })
+            .collect()
+    }).collect();
+
+    match FONT.iter().find(|(_, rows)| rows.iter().zip(glyph.iter()).all(|(a, b)| a == b)) {
+        Some(&(c, _)) => Ok(c),
+        None => err!("Unrecognised letter at column {}:\n{}", first_col, glyph.join("\n")),
+    }
+}
+
+fn read_message(grid: &[Vec<bool>]) -> Result<String> {
+    if grid.len() != GLYPH_HEIGHT {
+        return err!("Message is {} rows tall, expected {}", grid.len(), GLYPH_HEIGHT);
+    }
+
+    let width = grid[0].len();
+    let column_empty = |x: usize| grid.iter().all(|row| !row[x]);
+
+    let mut message = String::new();
+    let mut x = 0;
+    while x < width {
+        if column_empty(x) {
+            x += 1;
+            continue;
+        }
+        message.push(read_glyph(grid, x)?);
+        x += GLYPH_WIDTH;
+    }
+
+    Ok(message)
+}
+
+fn parse_stars(f_str: &str) -> Vec<Star> {
+    let star_re = Regex::new(r"position=<\s*(\S+),\s+(\S+)> velocity=<\s*(\S+),\s+(\S+)>").unwrap();
+    f_str.lines().map(|s| {
+        let caps = star_re.captures(s).unwrap();
+        Star::new(
+            caps.get(1).unwrap().as_str().parse().expect("string is empty"),
+            caps.get(2).unwrap().as_str().parse().expect("string is empty"),
+            caps.get(3).unwrap().as_str().parse().expect("string is empty"),
+            caps.get(4).unwrap().as_str().parse().expect("string is empty"),
+        )
+    }).collect()
+}
+
+pub fn q1(fname: String) -> String {
+    let mut f = File::open(fname).expect("File not found");
+
+    let mut f_str = String::new();
+    f.read_to_string(&mut f_str).expect("Couldn't find file");
+
+    _q1(&f_str).unwrap()
+}
+
+fn _q1(f_str: &str) -> Result<String> {
+    let star_list = parse_stars(f_str);
+    let t = message_time(&star_list);
+
+    read_message(&star_grid(&star_positions(&star_list, t)))
+}
+
+pub fn q2(fname: String) -> i64 {
+    let mut f = File::open(fname).expect("File not found");
+
+    let mut f_str = String::new();
+    f.read_to_string(&mut f_str).expect("Couldn't find file");
+
+    message_time(&parse_stars(&f_str))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_from(rows: &[&str]) -> Vec<Vec<bool>> {
+        rows.iter().map(|row| row.chars().map(|c| c == '#').collect()).collect()
+    }
+
+    #[test]
+    fn message_time_test() {
+        let star_list = parse_stars("
+            position=< 9,  1> velocity=< 0,  2>
+            position=< 7,  0> velocity=<-1,  0>
+            position=< 3, -2> velocity=<-1,  1>
+            position=< 6, 10> velocity=<-2, -1>
+            position=< 2, -4> velocity=< 2,  2>
+            position=<-6, 10> velocity=< 2, -2>
+            position=< 1,  8> velocity=< 1, -1>
+            position=< 1,  7> velocity=< 1,  0>
+            position=<-3, 11> velocity=< 1, -2>
+            position=< 7,  6> velocity=<-1, -1>
+            position=<-2,  3> velocity=< 1,  0>
+            position=<-4,  3> velocity=< 2,  0>
+            position=<10, -3> velocity=<-1,  1>
+            position=< 5, 11> velocity=< 1, -2>
+            position=< 4,  7> velocity=< 0, -1>
+            position=< 8, -2> velocity=< 0,  1>
+            position=<15,  0> velocity=<-2,  0>
+            position=< 1,  6> velocity=< 1,  0>
+            position=< 8,  9> velocity=< 0, -1>
+            position=< 3,  3> velocity=<-1,  1>
+            position=< 0,  5> velocity=< 0, -1>
+            position=<-2,  2> velocity=< 2,  0>
+            position=< 5, -2> velocity=< 1,  2>
+            position=< 1,  4> velocity=< 2,  1>
+            position=<-2,  7> velocity=< 2, -2>
+            position=< 3,  6> velocity=<-1, -1>
+            position=< 5,  0> velocity=< 1,  0>
+            position=<-6,  0> velocity=< 2,  0>
+            position=< 5,  9> velocity=< 1, -2>
+            position=<14,  7> velocity=<-2,  0>
+            position=<-3,  6> velocity=< 2, -1>
+        ".trim());
+        assert_eq!(message_time(&star_list), 3);
+    }
+
+    #[test]
+    fn read_message_test() {
+        let grid = grid_from(&[
+            "#....#..######..#.....",
+            "#....#..#.......#.....",
+            "#....#..#.......#.....",
+            "#....#..#.......#.....",
+            "######..#####...#.....",
+            "#....#..#.......#.....",
+            "#....#..#.......#.....",
+            "#....#..#.......#.....",
+            "#....#..#.......#.....",
+            "#....#..######..######",
+        ]);
+        assert_eq!(read_message(&grid).unwrap(), "HEL");
+    }
+
+    #[test]
+    fn read_message_rejects_unknown_glyphs() {
+        let grid = grid_from(&["#"; GLYPH_HEIGHT]);
+        assert!(read_message(&grid).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+const GRID_SIZE: usize = 300;
+
+fn power_level(x: u32, y: u32, serial_num: u32) -> i32 {
+    let rack_id = x + 10;
+    let mut power_level = rack_id * y;
+    power_level += serial_num;
+    power_level *= rack_id;
+
+    (((power_level / 100) as i32) % 10) - 5
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Square {
+    x: usize,
+    y: usize,
+    size: usize,
+    power: i64
+}
+
+// Cells are 1-indexed, as in the puzzle; row and column 0 of `sums` are all zero.
+struct SummedAreaTable {
+    width: usize,
+    height: usize,
+    sums: Vec<i64>
+}
+
+impl SummedAreaTable {
+    fn new<F: Fn(usize, usize) -> i64>(width: usize, height: usize, value_at: F) -> SummedAreaTable {
+        let stride = width + 1;
+        let mut sums = vec![0; stride * (height + 1)];
+        for y in 1..=height {
+            for x in 1..=width {
+                sums[y * stride + x] = value_at(x, y)
+                    + sums[(y - 1) * stride + x]
+                    + sums[y * stride + x - 1]
+                    - sums[(y - 1) * stride + x - 1];
+            }
+        }
+
+        SummedAreaTable { width, height, sums }
+    }
+
+    fn power_grid(serial_num: u32) -> SummedAreaTable {
+        SummedAreaTable::new(GRID_SIZE, GRID_SIZE, |x, y| i64::from(power_level(x as u32, y as u32, serial_num)))
+    }
+
+    fn at(&self, x: usize, y: usize) -> i64 {
+        self.sums[y * (self.width + 1) + x]
+    }
+
+    fn rect_sum(&self, x: usize, y: usize, width: usize, height: usize) -> i64 {
+        let (right, bottom) = (x + width - 1, y + height - 1);
+        self.at(right, bottom) - self.at(x - 1, bottom) - self.at(right, y - 1) + self.at(x - 1, y - 1)
+    }
+
+    fn square_sum(&self, x: usize, y: usize, size: usize) -> i64 {
+        self.rect_sum(x, y, size, size)
+    }
+
+    fn best_square_of_size(&self, size: usize) -> Option<Square> {
+        if size == 0 || size > self.width || size > self.height {
+            return None;
+        }
+
+        let mut best: Option<Square> = None;
+        for y in 1..=self.height - size + 1 {
+            for x in 1..=self.width - size + 1 {
+                let power = self.square_sum(x, y, size);
+                if best.is_none_or(|square| power > square.power) {
+                    best = Some(Square { x, y, size, power });
+                }
+            }
+        }
+        best
+    }
+
+    fn best_square(&self) -> Option<Square> {
+        let mut best: Option<Square> = None;
+        for size in 1..=self.width.min(self.height) {
+            if let Some(square) = self.best_square_of_size(size) {
+                if best.is_none_or(|b| square.power > b.power) {
+                    best = Some(square);
+                }
+            }
+        }
+        best
+    }
+}
+
+// No input file was saved for this day, so it isn't in the registry
+#[allow(dead_code)]
+pub fn q1(serial_num: u32) -> (u32, u32) {
+    let square = SummedAreaTable::power_grid(serial_num).best_square_of_size(3).unwrap();
+
+    (square.x as u32, square.y as u32)
+}
+
+#[allow(dead_code)]
+pub fn q2(serial_num: u32) -> (u32, u32, u32) {
+    let square = SummedAreaTable::power_grid(serial_num).best_square().unwrap();
+
+    (square.x as u32, square.y as u32, square.size as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_level_tests() {
+        assert_eq!(power_level(122, 79, 57), -5);
+        assert_eq!(power_level(217, 196, 39), 0);
+        assert_eq!(power_level(101, 153, 71), 4);
+    }
+
+    #[test]
+    fn summed_area_table_tests() {
+        let table = SummedAreaTable::new(4, 3, |x, y| (x * 10 + y) as i64);
+        assert_eq!(table.rect_sum(1, 1, 1, 1), 11);
+        assert_eq!(table.rect_sum(2, 2, 3, 2), 22 + 32 + 42 + 23 + 33 + 43);
+        assert_eq!(table.square_sum(1, 1, 3), (1..=3).map(|x| (1..=3).map(|y| x * 10 + y).sum::<i64>()).sum::<i64>());
+    }
+
+    #[test]
+    fn q1_tests() {
+        assert_eq!(q1(18), (33, 45));
+        assert_eq!(q1(42), (21, 61));
+        assert_eq!(SummedAreaTable::power_grid(18).best_square_of_size(3).unwrap().power, 29);
+    }
+
+    #[test]
+    fn q2_tests() {
+        assert_eq!(q2(18), (90, 269, 16));
+        assert_eq!(q2(42), (232, 251, 12));
+    }
+}
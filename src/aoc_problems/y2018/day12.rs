@@ -0,0 +1,309 @@
+use std::error::Error;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::prelude::*;
+use std::result;
+
+use regex::Regex;
+
+use aoc_problems::cycle;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+const WORD_BITS: usize = 64;
+
+fn char_to_bool(c: char) -> Result<bool> {
+    match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => err!("Unexpected char: {}", c),
+    }
+}
+
+// Bit `i` holds the pot numbered `offset + i`, with bits past `len` always clear. Always trimmed so
+// that the first and last bits are set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pots {
+    bits: Vec<u64>,
+    len: usize,
+    offset: i64
+}
+
+impl Pots {
+    fn from_bools<I: IntoIterator<Item = bool>>(pots: I, offset: i64) -> Pots {
+        let mut bits: Vec<u64> = Vec::new();
+        let mut len = 0;
+        for pot in pots {
+            if len % WORD_BITS == 0 {
+                bits.push(0);
+            }
+            if pot {
+                bits[len / WORD_BITS] |= 1 << (len % WORD_BITS);
+            }
+            len += 1;
+        }
+
+        Pots { bits, len, offset }.trimmed()
+    }
+
+    // The bits shifted `shift` places towards higher pots, so that bit `i` of word `word_idx`
+    // holds pot `word_idx * 64 + i - shift`
+    fn word_shifted_up(&self, word_idx: usize, shift: usize) -> u64 {
+        let word = self.bits.get(word_idx).cloned().unwrap_or(0);
+        if shift == 0 {
+            return word;
+        }
+        let carried = if word_idx == 0 { 0 } else { self.bits.get(word_idx - 1).cloned().unwrap_or(0) };
+        (word << shift) | (carried >> (WORD_BITS - shift))
+    }
+
+    fn trimmed(self) -> Pots {
+        let first_word = match self.bits.iter().position(|&word| word != 0) {
+            None => return Pots { bits: Vec::new(), len: 0, offset: 0 },
+            Some(word_idx) => word_idx,
+        };
+        let last_word = self.bits.iter().rposition(|&word| word != 0).unwrap();
+        let first = first_word * WORD_BITS + self.bits[first_word].trailing_zeros() as usize;
+        let last = last_word * WORD_BITS + WORD_BITS - 1 - self.bits[last_word].leading_zeros() as usize;
+
+        if first == 0 && last == self.len - 1 {
+            return self;
+        }
+
+        let len = last - first + 1;
+        let (word_shift, bit_shift) = (first / WORD_BITS, first % WORD_BITS);
+        let bits = (0..len.div_ceil(WORD_BITS)).map(|word_idx| {
+            let low = self.bits[word_idx + word_shift] >> bit_shift;
+            let high = match self.bits.get(word_idx + word_shift + 1) {
+                Some(word) if bit_shift > 0 => word << (WORD_BITS - bit_shift),
+                _ => 0,
+            };
+            low | high
+        }).collect();
+
+        Pots { bits, len, offset: self.offset + first as i64 }
+    }
+
+    fn plant_count(&self) -> i64 {
+        self.bits.iter().map(|word| i64::from(word.count_ones())).sum()
+    }
+
+    fn score(&self) -> i64 {
+        let mut score = 0;
+        for (word_idx, &word) in self.bits.iter().enumerate() {
+            let mut word = word;
+            while word != 0 {
+                score += self.offset + (word_idx * WORD_BITS) as i64 + i64::from(word.trailing_zeros());
+                word &= word - 1;
+            }
+        }
+        score
+    }
+}
+
+// The arrangement of plants without its position, so that a pattern which has drifted along the row
+// counts as a repeat
+#[derive(Debug, Clone)]
+struct Shape(Pots);
+
+impl PartialEq for Shape {
+    fn eq(&self, other: &Shape) -> bool {
+        self.0.len == other.0.len && self.0.bits == other.0.bits
+    }
+}
+
+impl Eq for Shape {}
+
+impl Hash for Shape {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.len.hash(state);
+        self.0.bits.hash(state);
+    }
+}
+
+struct Automaton {
+    rules: [bool; 32]
+}
+
+impl Automaton {
+    fn new(rule_list: &[String]) -> Result<Automaton> {
+        let transform_re = Regex::new(r"^([#.]{5}) => ([#.])$").unwrap();
+        let mut rules = [false; 32];
+        for rule in rule_list {
+            let cap = match transform_re.captures(rule.trim()) {
+                Some(cap) => cap,
+                None => return err!("Cannot parse rule: {}", rule),
+            };
+            let mut pattern: usize = 0;
+            for c in cap[1].chars() {
+                pattern = (pattern << 1) | char_to_bool(c)? as usize;
+            }
+            rules[pattern] = char_to_bool(cap[2].chars().next().unwrap())?;
+        }
+
+        if rules[0] {
+            return err!("Empty pots cannot grow plants, or the row would be infinite");
+        }
+
+        Ok(Automaton { rules })
+    }
+
+    // New bit `i` is decided by old pots `i - 4` to `i`, leftmost first, so the new row starts two
+    // pots further left. Each word of the new row is worked out at once: `neighbours[k]` holds, at
+    // every bit, the pot `k` to its left, and the rules that grow a plant are OR-ed together.
+    fn step(&self, pots: &Pots) -> Pots {
+        let bits = (0..(pots.len + 4).div_ceil(WORD_BITS)).map(|word_idx| {
+            let mut neighbours = [0u64; 5];
+            for (k, neighbour) in neighbours.iter_mut().enumerate() {
+                *neighbour = pots.word_shifted_up(word_idx, k);
+            }
+
+            let mut word = 0;
+            for pattern in (0..32).filter(|&pattern| self.rules[pattern]) {
+                // In `pattern` the leftmost pot is the highest bit, i.e. bit `k` is the pot `k` to the left
+                word |= neighbours.iter().enumerate().fold(!0, |matching, (k, &neighbour)| {
+                    matching & if pattern & (1 << k) != 0 { neighbour } else { !neighbour }
+                });
+            }
+            word
+        }).collect();
+
+        Pots { bits, len: pots.len + 4, offset: pots.offset - 2 }.trimmed()
+    }
+
+    // Once the arrangement of plants repeats, whether in place, drifting one pot per generation or
+    // drifting over a longer period, later generations are found from that cycle
+    fn score_after(&self, initial: &Pots, generations: u64) -> i64 {
+        let history = cycle::simulate_until_repeat(Shape(initial.clone()), generations, |shape| Shape(self.step(&shape.0)));
+
+        let cycle = match history.cycle {
+            Some(cycle) => cycle,
+            None => return history.states[generations as usize].0.score(),
+        };
+
+        let cycle_start = &history.states[cycle.start].0;
+        let drift = self.step(&history.states.last().unwrap().0).offset - cycle_start.offset;
+        let pots = &history.states[cycle.equivalent_step(generations)].0;
+        let periods = if generations < cycle.start as u64 { 0 } else { (generations - cycle.start as u64) / cycle.period as u64 };
+
+        pots.score() + periods as i64 * drift * pots.plant_count()
+    }
+}
+
+fn parse_input(input_list: &[String]) -> Result<(Automaton, Pots)> {
+    let initial_state_re = Regex::new(r"initial state: ([#.]+)").unwrap();
+
+    let init_state_cap = match input_list.first().and_then(|line| initial_state_re.captures(line)) {
+        Some(cap) => cap,
+        None => return err!("Cannot find initial state"),
+    };
+    let initial: Vec<bool> = init_state_cap[1].chars().map(char_to_bool).collect::<Result<_>>()?;
+
+    let rule_list: Vec<String> = input_list.iter().skip(1).filter(|s| !s.trim().is_empty()).cloned().collect();
+
+    Ok((Automaton::new(&rule_list)?, Pots::from_bools(initial, 0)))
+}
+
+fn read_input(fname: String) -> Vec<String> {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents.lines().map(|s| s.to_string()).collect()
+}
+
+pub fn q1(fname: String) -> i64 {
+    _q1(read_input(fname)).unwrap()
+}
+
+fn _q1(input_list: Vec<String>) -> Result<i64> {
+    let (automaton, pots) = parse_input(&input_list)?;
+
+    Ok(automaton.score_after(&pots, 20))
+}
+
+pub fn q2(fname: String) -> i64 {
+    _q2(read_input(fname)).unwrap()
+}
+
+fn _q2(input_list: Vec<String>) -> Result<i64> {
+    let (automaton, pots) = parse_input(&input_list)?;
+
+    Ok(automaton.score_after(&pots, 50_000_000_000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<String> {
+        "initial state: #..#.#..##......###...###
+
+        ...## => #
+        ..#.. => #
+        .#... => #
+        .#.#. => #
+        .#.## => #
+        .##.. => #
+        .#### => #
+        #.#.# => #
+        #.### => #
+        ##.#. => #
+        ##.## => #
+        ###.. => #
+        ###.# => #
+        ####. => #".lines().map(|s| s.trim().to_string()).collect()
+    }
+
+    #[test]
+    fn q1_test() {
+        assert_eq!(_q1(example()).unwrap(), 325);
+    }
+
+    #[test]
+    fn extrapolation_matches_simulation() {
+        let (automaton, initial) = parse_input(&example()).unwrap();
+
+        let mut pots = initial.clone();
+        for _ in 0..500 {
+            pots = automaton.step(&pots);
+        }
+
+        assert_eq!(automaton.score_after(&initial, 500), pots.score());
+    }
+
+    #[test]
+    fn step_matches_rules_across_words() {
+        let (automaton, _) = parse_input(&example()).unwrap();
+        let row: Vec<bool> = (0..150).map(|idx| idx % 7 == 0 || idx % 11 == 3 || idx == 63 || idx == 64).collect();
+        let pots = Pots::from_bools(row.clone(), -5);
+
+        // The rules applied one pot at a time
+        let pot = |idx: i64| idx >= 0 && (idx as usize) < row.len() && row[idx as usize];
+        let expected = Pots::from_bools((-2..row.len() as i64 + 2).map(|idx| {
+            let window = (idx - 2..=idx + 2).fold(0, |window, i| (window << 1) | pot(i) as usize);
+            automaton.rules[window]
+        }), -7);
+
+        assert_eq!(automaton.step(&pots), expected);
+    }
+
+    #[test]
+    fn extrapolates_patterns_drifting_over_several_generations() {
+        // `##` becomes `#.#`, which becomes `##` one pot to the right
+        let rules: Vec<String> = ["..##. => #", "##... => #", ".#.#. => #", "#.#.. => #"].iter().map(|s| s.to_string()).collect();
+        let automaton = Automaton::new(&rules).unwrap();
+        let initial = Pots::from_bools(vec![true, true], 0);
+
+        let mut pots = initial.clone();
+        for generation in 1..=200 {
+            pots = automaton.step(&pots);
+            assert_eq!(automaton.score_after(&initial, generation), pots.score());
+        }
+        assert_eq!(pots, Pots::from_bools(vec![true, true], 100));
+    }
+}
@@ -0,0 +1,421 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+
+use std::collections::HashSet;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+use self::Direction::*;
+
+impl Direction {
+    fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Up),
+            'v' => Some(Down),
+            '<' => Some(Left),
+            '>' => Some(Right),
+            _ => None
+        }
+    }
+
+    fn turn_left(self) -> Direction {
+        match self {
+            Up => Left,
+            Left => Down,
+            Down => Right,
+            Right => Up
+        }
+    }
+
+    fn turn_right(self) -> Direction {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Up => write!(f, "^"),
+            Down => write!(f, "v"),
+            Left => write!(f, "<"),
+            Right => write!(f, ">"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Track {
+    Empty,
+    Vertical,
+    Horizontal,
+    // `/`
+    ForwardCurve,
+    // `\`
+    BackwardCurve,
+    Intersection
+}
+
+impl Track {
+    fn from_char(c: char) -> Result<Track> {
+        match c {
+            ' ' => Ok(Track::Empty),
+            '|' | '^' | 'v' => Ok(Track::Vertical),
+            '-' | '<' | '>' => Ok(Track::Horizontal),
+            '/' => Ok(Track::ForwardCurve),
+            '\\' => Ok(Track::BackwardCurve),
+            '+' => Ok(Track::Intersection),
+            _ => err!("Cannot read track: {:?}", c),
+        }
+    }
+}
+
+impl fmt::Display for Track {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Track::Empty => write!(f, " "),
+            Track::Vertical => write!(f, "|"),
+            Track::Horizontal => write!(f, "-"),
+            Track::ForwardCurve => write!(f, "/"),
+            Track::BackwardCurve => write!(f, "\\"),
+            Track::Intersection => write!(f, "+"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Turn {
+    Left,
+    Straight,
+    Right
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cart {
+    id: usize,
+    x: usize,
+    y: usize,
+    dir: Direction,
+    next_turn: Turn
+}
+
+impl Cart {
+    fn new(id: usize, x: usize, y: usize, dir: Direction) -> Cart {
+        Cart {
+            id,
+            x,
+            y,
+            dir,
+            next_turn: Turn::Left
+        }
+    }
+
+    fn step_forward(&mut self) -> Result<()> {
+        match self.dir {
+            Up if self.y > 0 => self.y -= 1,
+            Left if self.x > 0 => self.x -= 1,
+            Down => self.y += 1,
+            Right => self.x += 1,
+            _ => return err!("Cart {} ran off the edge at ({}, {})", self.id, self.x, self.y),
+        }
+        Ok(())
+    }
+
+    fn follow(&mut self, track: Track) -> Result<()> {
+        self.dir = match (track, self.dir) {
+            (Track::Vertical, Up) | (Track::Vertical, Down) => self.dir,
+            (Track::Horizontal, Left) | (Track::Horizontal, Right) => self.dir,
+            (Track::ForwardCurve, Up) | (Track::ForwardCurve, Down) => self.dir.turn_right(),
+            (Track::ForwardCurve, _) => self.dir.turn_left(),
+            (Track::BackwardCurve, Up) | (Track::BackwardCurve, Down) => self.dir.turn_left(),
+            (Track::BackwardCurve, _) => self.dir.turn_right(),
+            (Track::Intersection, _) => {
+                let turn = self.next_turn;
+                self.next_turn = match turn {
+                    Turn::Left => Turn::Straight,
+                    Turn::Straight => Turn::Right,
+                    Turn::Right => Turn::Left,
+                };
+                match turn {
+                    Turn::Left => self.dir.turn_left(),
+                    Turn::Straight => self.dir,
+                    Turn::Right => self.dir.turn_right(),
+                }
+            },
+            (track, dir) => return err!("Cart {} cannot travel {:?} on {:?} at ({}, {})", self.id, dir, track, self.x, self.y),
+        };
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Crash {
+    tick: usize,
+    x: usize,
+    y: usize,
+    moving_cart: usize,
+    struck_cart: usize
+}
+
+impl fmt::Display for Crash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "tick {}: cart {} hit cart {} at {},{}", self.tick, self.moving_cart, self.struck_cart, self.x, self.y)
+    }
+}
+
+struct Mine {
+    tracks: Vec<Vec<Track>>,
+    carts: Vec<Cart>,
+    tick_count: usize,
+    crash_log: Vec<Crash>
+}
+
+impl Mine {
+    fn new(text_lines: &[String]) -> Result<Mine> {
+        let width = text_lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+
+        let mut tracks: Vec<Vec<Track>> = Vec::new();
+        let mut carts: Vec<Cart> = Vec::new();
+        for (y, line) in text_lines.iter().enumerate() {
+            let mut row: Vec<Track> = Vec::with_capacity(width);
+            for (x, c) in line.chars().enumerate() {
+                if let Some(dir) = Direction::from_char(c) {
+                    let id = carts.len();
+                    carts.push(Cart::new(id, x, y, dir));
+                }
+                row.push(Track::from_char(c)?);
+            }
+            row.resize(width, Track::Empty);
+            tracks.push(row);
+        }
+
+        Ok(Mine { tracks, carts, tick_count: 0, crash_log: Vec::new() })
+    }
+
+    fn track_at(&self, x: usize, y: usize) -> Track {
+        self.tracks.get(y).and_then(|row| row.get(x)).cloned().unwrap_or(Track::Empty)
+    }
+
+    fn tick(&mut self) -> Result<Vec<Crash>> {
+        self.carts.sort_by_key(|cart| (cart.y, cart.x));
+
+        let mut crashed: Vec<bool> = vec![false; self.carts.len()];
+        let mut crashes: Vec<Crash> = Vec::new();
+        for idx in 0..self.carts.len() {
+            if crashed[idx] {
+                continue;
+            }
+
+            self.carts[idx].step_forward()?;
+            let (x, y) = (self.carts[idx].x, self.carts[idx].y);
+
+            let struck = (0..self.carts.len())
+                .find(|&other| other != idx && !crashed[other] && self.carts[other].x == x && self.carts[other].y == y);
+            if let Some(other) = struck {
+                crashed[idx] = true;
+                crashed[other] = true;
+                crashes.push(Crash {
+                    tick: self.tick_count,
+                    x,
+                    y,
+                    moving_cart: self.carts[idx].id,
+                    struck_cart: self.carts[other].id
+                });
+                continue;
+            }
+
+            let track = self.track_at(x, y);
+            self.carts[idx].follow(track)?;
+        }
+
+        let mut crashed = crashed.into_iter();
+        self.carts.retain(|_| !crashed.next().unwrap());
+        self.tick_count += 1;
+        self.crash_log.extend(crashes.iter().cloned());
+
+        Ok(crashes)
+    }
+
+    // Where every cart is, which way it's heading and how it turns next, in reading order
+    fn cart_states(&self) -> Vec<(usize, usize, Direction, Turn)> {
+        let mut states: Vec<_> = self.carts.iter().map(|cart| (cart.y, cart.x, cart.dir, cart.next_turn)).collect();
+        states.sort_by_key(|&(y, x, _, _)| (y, x));
+        states
+    }
+
+    // Ticks until at least one crash. Carts that get back to states they were all in before will
+    // go round the same loop forever, so that fails rather than ticking on.
+    fn tick_until_crash(&mut self) -> Result<Vec<Crash>> {
+        if self.carts.len() < 2 {
+            return err!("Fewer than two carts left, so nothing can crash");
+        }
+
+        let mut seen: HashSet<Vec<(usize, usize, Direction, Turn)>> = HashSet::new();
+        while seen.insert(self.cart_states()) {
+            let crashes = self.tick()?;
+            if !crashes.is_empty() {
+                return Ok(crashes);
+            }
+        }
+
+        err!("After {} ticks the {} carts are repeating themselves, so they will never crash", self.tick_count, self.carts.len())
+    }
+
+    fn first_crash(&mut self) -> Result<Crash> {
+        Ok(self.tick_until_crash()?[0])
+    }
+
+    fn last_cart_standing(&mut self) -> Result<Cart> {
+        while self.carts.len() > 1 {
+            self.tick_until_crash()?;
+        }
+
+        match self.carts.first() {
+            Some(&cart) => Ok(cart),
+            None => err!("Every cart crashed; crashes were:\n{}", self.crash_log.iter().map(|c| c.to_string()).collect::<Vec<_>>().join("\n")),
+        }
+    }
+}
+
+impl fmt::Display for Mine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.tracks.iter().enumerate() {
+            for (x, track) in row.iter().enumerate() {
+                match self.carts.iter().find(|cart| cart.x == x && cart.y == y) {
+                    Some(cart) => write!(f, "{}", cart.dir)?,
+                    None => write!(f, "{}", track)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn read_lines(fname: String) -> Vec<String> {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents.lines().map(|x: &str| {
+        x.to_string()
+    }).collect()
+}
+
+pub fn q1(fname: String) -> (usize, usize) {
+    _q1(read_lines(fname)).unwrap()
+}
+
+fn _q1(text_lines: Vec<String>) -> Result<(usize, usize)> {
+    let crash = Mine::new(&text_lines)?.first_crash()?;
+
+    Ok((crash.x, crash.y))
+}
+
+pub fn q2(fname: String) -> (usize, usize) {
+    _q2(read_lines(fname)).unwrap()
+}
+
+fn _q2(text_lines: Vec<String>) -> Result<(usize, usize)> {
+    let cart = Mine::new(&text_lines)?.last_cart_standing()?;
+
+    Ok((cart.x, cart.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn q1_test() {
+        assert_eq!(
+            _q1(vec![
+                r"/->-\        ".to_string(),
+                r"|   |  /----\".to_string(),
+                r"| /-+--+-\  |".to_string(),
+                r"| | |  | v  |".to_string(),
+                r"\-+-/  \-+--/".to_string(),
+                r"  \------/   ".to_string(),
+            ]).unwrap(),
+            (7, 3)
+        );
+    }
+
+    #[test]
+    fn q2_test() {
+        assert_eq!(
+            _q2(vec![
+                r"/>-<\  ".to_string(),
+                r"|   |  ".to_string(),
+                r"| /<+-\".to_string(),
+                r"| | | v".to_string(),
+                r"\>+</ |".to_string(),
+                r"  |   ^".to_string(),
+                r"  \<->/".to_string(),
+            ]).unwrap(),
+            (6, 4)
+        );
+    }
+
+    #[test]
+    fn crash_log_test() {
+        let mut mine = Mine::new(&[
+            r"/>-<\  ".to_string(),
+            r"|   |  ".to_string(),
+            r"| /<+-\".to_string(),
+            r"| | | v".to_string(),
+            r"\>+</ |".to_string(),
+            r"  |   ^".to_string(),
+            r"  \<->/".to_string(),
+        ]).unwrap();
+        mine.last_cart_standing().unwrap();
+
+        assert_eq!(
+            mine.crash_log,
+            vec![
+                Crash { tick: 0, x: 2, y: 0, moving_cart: 1, struck_cart: 0 },
+                Crash { tick: 0, x: 2, y: 4, moving_cart: 5, struck_cart: 4 },
+                Crash { tick: 0, x: 6, y: 4, moving_cart: 6, struck_cart: 3 },
+                Crash { tick: 2, x: 2, y: 4, moving_cart: 7, struck_cart: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn carts_that_never_meet_test() {
+        // Two carts chasing each other round a loop, and a lone cart
+        let chase = [
+            r"/>-\".to_string(),
+            r"|  |".to_string(),
+            r"\-</".to_string(),
+        ];
+        assert!(Mine::new(&chase).unwrap().first_crash().is_err());
+        assert!(Mine::new(&chase).unwrap().last_cart_standing().is_err());
+
+        let lone = [
+            r"/>-\".to_string(),
+            r"\--/".to_string(),
+        ];
+        assert!(Mine::new(&lone).unwrap().first_crash().is_err());
+        assert_eq!(_q2(lone.to_vec()).unwrap(), (1, 0));
+    }
+}
@@ -0,0 +1,207 @@
+use std::error::Error;
+use std::result;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+// How far part 2 looks before deciding the scores never appear. Puzzle answers are around 20
+// million.
+const MAX_RECIPES: usize = 100_000_000;
+
+struct Scoreboard {
+    recipes: Vec<u8>,
+    elves: Vec<usize>,
+    emitted: usize
+}
+
+impl Scoreboard {
+    // Any starting recipes and number of elves, though the puzzle only uses `standard`
+    #[allow(dead_code)]
+    fn new(initial_recipes: &[u8], elf_count: usize) -> Result<Scoreboard> {
+        if elf_count == 0 || elf_count > initial_recipes.len() {
+            return err!("Need between 1 and {} elves, got {}", initial_recipes.len(), elf_count);
+        }
+        if let Some(score) = initial_recipes.iter().find(|&&d| d >= 10) {
+            return err!("Recipe scores must be single digits, got {}", score);
+        }
+
+        Ok(Scoreboard {
+            recipes: initial_recipes.to_vec(),
+            elves: (0..elf_count).collect(),
+            emitted: 0
+        })
+    }
+
+    // The two elves with their first two recipes, as in the puzzle
+    fn standard() -> Scoreboard {
+        Scoreboard {
+            recipes: vec![3, 7],
+            elves: vec![0, 1],
+            emitted: 0
+        }
+    }
+
+    fn create_recipes(&mut self) {
+        let new_recipe_score: usize = self.elves.iter().map(|&idx| self.recipes[idx] as usize).sum();
+
+        let first_new = self.recipes.len();
+        let mut score = new_recipe_score;
+        loop {
+            self.recipes.push((score % 10) as u8);
+            score /= 10;
+            if score == 0 {
+                break;
+            }
+        }
+        self.recipes[first_new..].reverse();
+
+        for idx in self.elves.iter_mut() {
+            *idx = (*idx + 1 + self.recipes[*idx] as usize) % self.recipes.len();
+        }
+    }
+}
+
+impl Iterator for Scoreboard {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        while self.emitted >= self.recipes.len() {
+            self.create_recipes();
+        }
+
+        self.emitted += 1;
+        Some(self.recipes[self.emitted - 1])
+    }
+}
+
+// Knuth-Morris-Pratt matcher, fed one digit at a time
+struct StreamMatcher {
+    pattern: Vec<u8>,
+    failure: Vec<usize>,
+    matched: usize
+}
+
+impl StreamMatcher {
+    fn new(pattern: Vec<u8>) -> StreamMatcher {
+        let mut failure = vec![0; pattern.len()];
+        let mut k = 0;
+        for i in 1..pattern.len() {
+            while k > 0 && pattern[i] != pattern[k] {
+                k = failure[k - 1];
+            }
+            if pattern[i] == pattern[k] {
+                k += 1;
+            }
+            failure[i] = k;
+        }
+
+        StreamMatcher { pattern, failure, matched: 0 }
+    }
+
+    fn push(&mut self, digit: u8) -> bool {
+        if self.matched == self.pattern.len() {
+            self.matched = self.failure[self.matched - 1];
+        }
+        while self.matched > 0 && self.pattern[self.matched] != digit {
+            self.matched = self.failure[self.matched - 1];
+        }
+        if self.pattern[self.matched] == digit {
+            self.matched += 1;
+        }
+
+        self.matched == self.pattern.len()
+    }
+}
+
+// No input file was saved for this day, so it isn't in the registry
+#[allow(dead_code)]
+pub fn q1(min_recipes: usize) -> String {
+    Scoreboard::standard()
+        .skip(min_recipes)
+        .take(10)
+        .map(|digit| digit.to_string())
+        .collect()
+}
+
+// Where `sublist` first starts, if it ends within the first `max_recipes` recipes
+fn first_appearance(scoreboard: Scoreboard, sublist: Vec<u8>, max_recipes: usize) -> Option<usize> {
+    if sublist.is_empty() {
+        return Some(0);
+    }
+
+    let pattern_len = sublist.len();
+    let mut matcher = StreamMatcher::new(sublist);
+    scoreboard
+        .take(max_recipes)
+        .enumerate()
+        .find(|&(_, digit)| matcher.push(digit))
+        .map(|(idx, _)| idx + 1 - pattern_len)
+}
+
+#[allow(dead_code)]
+pub fn q2(substring: String) -> usize {
+    _q2(substring, MAX_RECIPES).unwrap()
+}
+
+fn _q2(substring: String, max_recipes: usize) -> Result<usize> {
+    let sublist: Vec<u8> = substring
+        .trim()
+        .chars()
+        .map(|c| c.to_digit(10).map(|d| d as u8).ok_or_else(|| format!("Not a digit: {:?}", c)))
+        .collect::<result::Result<_, _>>()?;
+
+    match first_appearance(Scoreboard::standard(), sublist, max_recipes) {
+        Some(idx) => Ok(idx),
+        None => err!("The scores never appear in the first {} recipes", max_recipes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn q1_tests() {
+        assert_eq!(q1(9), "5158916779");
+        assert_eq!(q1(5), "0124515891");
+        assert_eq!(q1(18), "9251071085");
+        assert_eq!(q1(2018), "5941429882");
+    }
+
+    #[test]
+    fn q2_tests() {
+        assert_eq!(q2("51589".to_string()), 9);
+        assert_eq!(q2("01245".to_string()), 5);
+        assert_eq!(q2("92510".to_string()), 18);
+        assert_eq!(q2("59414".to_string()), 2018);
+
+        // 59414 ends with the 2023rd recipe
+        assert_eq!(first_appearance(Scoreboard::standard(), vec![5, 9, 4, 1, 4], 2023), Some(2018));
+        assert_eq!(first_appearance(Scoreboard::standard(), vec![5, 9, 4, 1, 4], 2022), None);
+        assert!(_q2("59414".to_string(), 2022).is_err());
+    }
+
+    #[test]
+    fn scoreboard_tests() {
+        let digits: Vec<u8> = Scoreboard::standard().take(20).collect();
+        assert_eq!(digits, vec![3, 7, 1, 0, 1, 0, 1, 2, 4, 5, 1, 5, 8, 9, 1, 6, 7, 7, 9, 2]);
+
+        let digits: Vec<u8> = Scoreboard::new(&[9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9], 12).unwrap().take(15).collect();
+        assert_eq!(digits, vec![9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 1, 0, 8]);
+
+        assert!(Scoreboard::new(&[3, 7], 3).is_err());
+        assert!(Scoreboard::new(&[3, 7], 0).is_err());
+        assert!(Scoreboard::new(&[3, 17], 2).is_err());
+        assert!(_q2("59x14".to_string(), MAX_RECIPES).is_err());
+    }
+
+    #[test]
+    fn stream_matcher_tests() {
+        let mut matcher = StreamMatcher::new(vec![1, 1, 2]);
+        let hits: Vec<bool> = [1, 1, 1, 2, 1, 1, 2].iter().map(|&d| matcher.push(d)).collect();
+        assert_eq!(hits, vec![false, false, false, true, false, false, true]);
+    }
+}
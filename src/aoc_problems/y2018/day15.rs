@@ -0,0 +1,1240 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+use std::str::FromStr;
+use std::thread;
+
+use aoc_problems::animation::{Animator, Colour, Frame, Glyph, Line, Target};
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Species {
+    GOBLIN,
+    ELF
+}
+
+impl fmt::Display for Species {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Species::GOBLIN => write!(f, "Goblin"),
+            Species::ELF => write!(f, "Elf"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+struct Coordinate {
+    x: usize,
+    y: usize
+}
+
+impl Ord for Coordinate {
+    fn cmp(&self, other: &Coordinate) -> cmp::Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+impl PartialOrd for Coordinate {
+    fn partial_cmp(&self, other: &Coordinate) -> Option<cmp::Ordering> {
+        Some((self.y, self.x).cmp(&(other.y, other.x)))
+    }
+}
+
+impl fmt::Debug for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct SpeciesStats {
+    attack: usize,
+    health: usize
+}
+
+impl Default for SpeciesStats {
+    fn default() -> SpeciesStats {
+        SpeciesStats { attack: 3, health: 200 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Initiative {
+    ReadingOrder,
+    SpeciesFirst(Species)
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum MovementRule {
+    TowardsNearestTarget,
+    Stationary
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct CombatConfig {
+    elf: SpeciesStats,
+    goblin: SpeciesStats,
+    initiative: Initiative,
+    movement: MovementRule,
+    max_rounds: Option<usize>,
+    abort_on_elf_death: bool
+}
+
+impl Default for CombatConfig {
+    fn default() -> CombatConfig {
+        CombatConfig {
+            elf: SpeciesStats::default(),
+            goblin: SpeciesStats::default(),
+            initiative: Initiative::ReadingOrder,
+            movement: MovementRule::TowardsNearestTarget,
+            max_rounds: None,
+            abort_on_elf_death: false
+        }
+    }
+}
+
+impl CombatConfig {
+    fn with_elf_attack(self, attack: usize) -> CombatConfig {
+        CombatConfig { elf: SpeciesStats { attack, ..self.elf }, ..self }
+    }
+
+    fn without_elf_losses(self) -> CombatConfig {
+        CombatConfig { abort_on_elf_death: true, ..self }
+    }
+
+    fn stats_for(&self, species: Species) -> SpeciesStats {
+        match species {
+            Species::ELF => self.elf,
+            Species::GOBLIN => self.goblin,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Character {
+    id: usize,
+    species: Species,
+    health: usize,
+    attack: usize,
+    position: usize
+}
+
+impl Character {
+    fn new(id: usize, species: Species, stats: SpeciesStats, position: usize) -> Character {
+        Character {
+            id,
+            species,
+            health: stats.health,
+            attack: stats.attack,
+            position
+        }
+    }
+
+    fn take_damage(&mut self, atk: usize) -> bool {
+        if atk >= self.health {
+            self.health = 0;
+            return true;
+        }
+
+        self.health -= atk;
+
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Open,
+    Wall
+}
+
+impl Cell {
+    fn is_open(self) -> bool {
+        self == Cell::Open
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Cell::Open => write!(f, "."),
+            Cell::Wall => write!(f, "#"),
+        }
+    }
+}
+
+impl FromStr for Cell {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Cell> {
+        match s.as_bytes().get(0) {
+            None => err!("empty string doesn't work"),
+            Some(&b'.') => Ok(Cell::Open),
+            Some(&b'#') => Ok(Cell::Wall),
+            Some(&b) => err!("Cannot read: 0x{:X}", b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum CombatEvent {
+    Move { round: usize, unit: usize, species: Species, from: Coordinate, to: Coordinate },
+    Attack { round: usize, unit: usize, species: Species, target: usize, at: Coordinate, damage: usize, remaining_health: usize },
+    Death { round: usize, unit: usize, species: Species, at: Coordinate }
+}
+
+impl CombatEvent {
+    fn round(&self) -> usize {
+        match *self {
+            CombatEvent::Move { round, .. } | CombatEvent::Attack { round, .. } | CombatEvent::Death { round, .. } => round,
+        }
+    }
+}
+
+impl fmt::Display for CombatEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CombatEvent::Move { round, unit, species, from, to } => {
+                write!(f, "Round {}: {} {} moves {} -> {}", round, species, unit, from, to)
+            },
+            CombatEvent::Attack { round, unit, species, target, at, damage, remaining_health } => {
+                write!(f, "Round {}: {} {} hits unit {} at {} for {} ({} HP left)", round, species, unit, target, at, damage, remaining_health)
+            },
+            CombatEvent::Death { round, unit, species, at } => {
+                write!(f, "Round {}: {} {} dies at {}", round, species, unit, at)
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum TurnResult {
+    Acted(Action),
+    Idle,
+    CombatOver,
+    ElfLost
+}
+
+// What a unit did with its turn
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+struct Action {
+    moved: bool,
+    // The unit attacked, and whether the attack killed it
+    attacked: Option<(usize, bool)>
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum RoundResult {
+    Completed,
+    CombatOver,
+    ElfLost,
+    // Nobody could move or attack, so nobody ever will
+    Stalemate,
+    // The config's round limit was reached with both sides still standing
+    OutOfRounds
+}
+
+#[derive(Default, Clone, Copy)]
+struct SearchNode {
+    search: u32,
+    dist: u32,
+    nearest_target: u32
+}
+
+// Scratch space for the breadth-first search, reused between turns
+#[derive(Default, Clone)]
+struct Pathfinder {
+    nodes: Vec<SearchNode>,
+    search: u32,
+    frontier: Vec<usize>,
+    next_frontier: Vec<usize>
+}
+
+impl Pathfinder {
+    fn new(size: usize) -> Pathfinder {
+        Pathfinder {
+            nodes: vec![SearchNode::default(); size],
+            search: 0,
+            frontier: Vec::new(),
+            next_frontier: Vec::new()
+        }
+    }
+
+    fn start_search(&mut self) {
+        self.search += 1;
+        self.frontier.clear();
+    }
+
+    fn reached(&self, idx: usize) -> Option<SearchNode> {
+        Some(self.nodes[idx]).filter(|node| node.search == self.search)
+    }
+}
+
+// The grid is stored row by row with a border of wall around it, so every open square has four
+// neighbours without bounds checks, and comparing indices compares squares in reading order.
+#[derive(Default, Clone)]
+struct Map {
+    grid: Vec<Cell>,
+    width: usize,
+    height: usize,
+    stride: usize,
+    units: Vec<Character>,
+    occupant: Vec<Option<usize>>,
+    passable: Vec<bool>,
+    config: CombatConfig,
+    initial_elf_count: usize,
+    rounds: usize,
+    events: Option<Vec<CombatEvent>>,
+    pathfinder: Pathfinder
+}
+
+impl Map {
+    fn new(input_grid: Vec<Vec<char>>) -> Result<Map> {
+        Map::with_config(input_grid, CombatConfig::default())
+    }
+
+    fn with_config(input_grid: Vec<Vec<char>>, config: CombatConfig) -> Result<Map> {
+        let height = input_grid.len();
+        let width = input_grid.first().map_or(0, |row| row.len());
+        if input_grid.iter().any(|row| row.len() != width) {
+            return err!("Map rows must all be {} wide", width);
+        }
+
+        let stride = width + 2;
+        let size = stride * (height + 2);
+        let mut grid = vec![Cell::Wall; size];
+        let mut units = Vec::new();
+        let mut occupant = vec![None; size];
+        for (y, row) in input_grid.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                let idx = (y + 1) * stride + x + 1;
+                let species = match cell {
+                    'G' => Species::GOBLIN,
+                    'E' => Species::ELF,
+                    cell => {
+                        grid[idx] = cell.to_string().parse()?;
+                        continue;
+                    }
+                };
+                occupant[idx] = Some(units.len());
+                units.push(Character::new(units.len(), species, config.stats_for(species), idx));
+                grid[idx] = Cell::Open;
+            }
+        }
+
+        let passable = (0..size).map(|idx| grid[idx].is_open() && occupant[idx].is_none()).collect();
+        let initial_elf_count = units.iter().filter(|c| c.species == Species::ELF).count();
+
+        Ok(Map {
+            grid,
+            width,
+            height,
+            stride,
+            units,
+            occupant,
+            passable,
+            config,
+            initial_elf_count,
+            rounds: 0,
+            events: None,
+            pathfinder: Pathfinder::new(size)
+        })
+    }
+
+    fn coordinate(&self, idx: usize) -> Coordinate {
+        Coordinate { x: idx % self.stride - 1, y: idx / self.stride - 1 }
+    }
+
+    fn index(&self, c: Coordinate) -> usize {
+        (c.y + 1) * self.stride + c.x + 1
+    }
+
+    // Only valid for squares inside the border; returned in reading order
+    fn neighbours(&self, idx: usize) -> [usize; 4] {
+        [idx - self.stride, idx - 1, idx + 1, idx + self.stride]
+    }
+
+    fn living_units(&self) -> impl Iterator<Item = &Character> {
+        self.units.iter().filter(|c| c.health > 0)
+    }
+
+    fn record_events(&mut self) {
+        self.events = Some(Vec::new());
+    }
+
+    fn record(&mut self, event: CombatEvent) {
+        if let Some(events) = self.events.as_mut() {
+            events.push(event);
+        }
+    }
+
+    fn outcome(&mut self) -> Result<usize> {
+        loop {
+            match self.increment()? {
+                RoundResult::Completed => continue,
+                RoundResult::CombatOver => return Ok(self.rounds * self.total_health()),
+                RoundResult::ElfLost => return err!("An elf died in round {}", self.rounds + 1),
+                RoundResult::Stalemate => return err!("Stalemate: nothing happened in round {}", self.rounds + 1),
+                RoundResult::OutOfRounds => return err!("Combat still going after {} rounds", self.rounds),
+            }
+        }
+    }
+
+    fn any_elves_lost(&self) -> bool {
+        self.initial_elf_count != self.living_units().filter(|c| c.species == Species::ELF).count()
+    }
+
+    fn elfy_outcome(&mut self) -> Result<Option<usize>> {
+        loop {
+            match self.increment()? {
+                RoundResult::Completed => continue,
+                RoundResult::CombatOver if self.any_elves_lost() => return Ok(None),
+                RoundResult::CombatOver => return Ok(Some(self.rounds * self.total_health())),
+                RoundResult::ElfLost | RoundResult::Stalemate | RoundResult::OutOfRounds => return Ok(None),
+            }
+        }
+    }
+
+    // A single search outwards from every square in range of an enemy. Each square learns its
+    // distance to the nearest of them and, among those equally near, the first in reading order,
+    // which is all the unit needs to pick both its destination and its first step.
+    fn next_step(&mut self, unit: usize) -> Option<usize> {
+        let start = self.units[unit].position;
+        let species = self.units[unit].species;
+
+        let mut pathfinder = std::mem::take(&mut self.pathfinder);
+        pathfinder.start_search();
+        let search = pathfinder.search;
+
+        for enemy in self.living_units().filter(|c| c.species != species) {
+            for idx in self.neighbours(enemy.position) {
+                if self.passable[idx] && pathfinder.nodes[idx].search != search {
+                    pathfinder.nodes[idx] = SearchNode { search, dist: 0, nearest_target: idx as u32 };
+                    pathfinder.frontier.push(idx);
+                }
+            }
+        }
+
+        // Expand one distance at a time until a square next to the unit is reached, at which
+        // point those squares have their final nearest target
+        let around_start = self.neighbours(start);
+        let mut dist = 0;
+        while !pathfinder.frontier.is_empty() && !around_start.iter().any(|&idx| pathfinder.reached(idx).is_some()) {
+            pathfinder.next_frontier.clear();
+            for &idx in pathfinder.frontier.iter() {
+                let nearest_target = pathfinder.nodes[idx].nearest_target;
+                for neighbour in self.neighbours(idx) {
+                    if !self.passable[neighbour] {
+                        continue;
+                    }
+                    let next = &mut pathfinder.nodes[neighbour];
+                    if next.search != search {
+                        *next = SearchNode { search, dist: dist + 1, nearest_target };
+                        pathfinder.next_frontier.push(neighbour);
+                    } else if next.dist == dist + 1 && nearest_target < next.nearest_target {
+                        next.nearest_target = nearest_target;
+                    }
+                }
+            }
+            std::mem::swap(&mut pathfinder.frontier, &mut pathfinder.next_frontier);
+            dist += 1;
+        }
+
+        let step = self.neighbours(start)
+            .iter()
+            .filter(|&&idx| self.passable[idx])
+            .filter_map(|&idx| pathfinder.reached(idx).map(|node| (node.dist, node.nearest_target, idx)))
+            .min()
+            .map(|(_, _, idx)| idx);
+
+        self.pathfinder = pathfinder;
+        step
+    }
+
+    fn next_target(&self, unit: usize) -> Option<usize> {
+        let character = &self.units[unit];
+        self.neighbours(character.position)
+            .iter()
+            .filter_map(|&idx| self.occupant[idx])
+            .filter(|&other| self.units[other].species != character.species)
+            .min_by_key(|&enemy| (self.units[enemy].health, self.units[enemy].position))
+    }
+
+    fn move_unit(&mut self, unit: usize, to: usize) {
+        let from = self.units[unit].position;
+        self.occupant[from] = None;
+        self.passable[from] = true;
+        self.occupant[to] = Some(unit);
+        self.passable[to] = false;
+        self.units[unit].position = to;
+    }
+
+    fn remove_unit(&mut self, unit: usize) {
+        let position = self.units[unit].position;
+        self.units[unit].health = 0;
+        self.occupant[position] = None;
+        self.passable[position] = true;
+    }
+
+    fn attack(&mut self, attacker: usize, target: usize) -> Option<Character> {
+        let round = self.rounds + 1;
+        let attacker = self.units[attacker];
+
+        let succumb = self.units[target].take_damage(attacker.attack);
+        let defender = self.units[target];
+        let at = self.coordinate(defender.position);
+
+        self.record(CombatEvent::Attack {
+            round,
+            unit: attacker.id,
+            species: attacker.species,
+            target: defender.id,
+            at,
+            damage: attacker.attack,
+            remaining_health: defender.health
+        });
+
+        if succumb {
+            self.remove_unit(target);
+            self.record(CombatEvent::Death { round, unit: defender.id, species: defender.species, at });
+            return Some(defender);
+        }
+
+        None
+    }
+
+    fn turn_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = self.living_units().map(|c| c.id).collect();
+        match self.config.initiative {
+            Initiative::ReadingOrder => order.sort_by_key(|&unit| self.units[unit].position),
+            Initiative::SpeciesFirst(species) => {
+                order.sort_by_key(|&unit| (self.units[unit].species != species, self.units[unit].position))
+            },
+        }
+        order
+    }
+
+    fn take_turn(&mut self, unit: usize, round: usize) -> TurnResult {
+        let species = self.units[unit].species;
+        if !self.living_units().any(|c| c.species != species) {
+            return TurnResult::CombatOver;
+        }
+
+        let mut action = Action::default();
+        if self.config.movement == MovementRule::TowardsNearestTarget && self.next_target(unit).is_none() {
+            if let Some(step) = self.next_step(unit) {
+                let from = self.coordinate(self.units[unit].position);
+                self.move_unit(unit, step);
+                self.record(CombatEvent::Move { round, unit, species, from, to: self.coordinate(step) });
+
+                action.moved = true;
+            }
+        }
+
+        // attack
+        let target_to_attack = match self.next_target(unit) {
+            None if action.moved => return TurnResult::Acted(action),
+            None => return TurnResult::Idle,
+            Some(target) => target
+        };
+
+        let killed = self.attack(unit, target_to_attack);
+        if self.config.abort_on_elf_death && killed.is_some_and(|c| c.species == Species::ELF) {
+            return TurnResult::ElfLost;
+        }
+
+        action.attacked = Some((target_to_attack, killed.is_some()));
+        TurnResult::Acted(action)
+    }
+
+    fn increment(&mut self) -> Result<RoundResult> {
+        self.increment_with(|_, _, _| Ok(()))
+    }
+
+    fn increment_with<F: FnMut(&Map, usize, Action) -> Result<()>>(&mut self, mut after_turn: F) -> Result<RoundResult> {
+        if let Some(max_rounds) = self.config.max_rounds {
+            if self.rounds >= max_rounds {
+                return Ok(RoundResult::OutOfRounds);
+            }
+        }
+
+        let round = self.rounds + 1;
+        let mut something_happened: bool = false;
+        for unit in self.turn_order() {
+            if self.units[unit].health == 0 {
+                continue;
+            }
+
+            let action = match self.take_turn(unit, round) {
+                TurnResult::CombatOver => return Ok(RoundResult::CombatOver),
+                TurnResult::ElfLost => return Ok(RoundResult::ElfLost),
+                TurnResult::Acted(action) => {
+                    something_happened = true;
+                    action
+                },
+                TurnResult::Idle => Action::default(),
+            };
+
+            after_turn(self, unit, action)?;
+        }
+
+        if !something_happened {
+            return Ok(RoundResult::Stalemate);
+        }
+
+        self.rounds = round;
+        Ok(RoundResult::Completed)
+    }
+
+    fn animate(&mut self, animator: &mut Animator) -> Result<usize> {
+        animator.show(self.frame(None))?;
+        loop {
+            let result = self.increment_with(|map, unit, action| Ok(animator.show(map.frame(Some((unit, action))))?))?;
+            match result {
+                RoundResult::Completed => continue,
+                RoundResult::CombatOver => {
+                    animator.show(self.frame(None))?;
+                    return Ok(self.rounds * self.total_health());
+                },
+                RoundResult::ElfLost => return err!("An elf died in round {}", self.rounds + 1),
+                RoundResult::Stalemate => return err!("Stalemate: nothing happened in round {}", self.rounds + 1),
+                RoundResult::OutOfRounds => return err!("Combat still going after {} rounds", self.rounds),
+            }
+        }
+    }
+
+    fn frame(&self, active: Option<(usize, Action)>) -> Frame {
+        let caption = match active {
+            Some((unit, action)) => {
+                let attack = action.attacked.map(|(target, killed)| {
+                    format!("{} {} {}", if killed { "killed" } else { "attacked" }, self.units[target].species, target)
+                });
+                let deed = match (action.moved, attack) {
+                    (false, None) => "waited".to_string(),
+                    (true, None) => "moved".to_string(),
+                    (false, Some(attack)) => attack,
+                    (true, Some(attack)) => format!("moved and {}", attack),
+                };
+                format!("Round {}: {} {} {}", self.rounds + 1, self.units[unit].species, unit, deed)
+            },
+            None => format!("After {} full rounds", self.rounds),
+        };
+
+        let lines = (0..self.height).map(|y| {
+            let row = self.row(y);
+            let glyphs = row.iter().map(|&idx| {
+                let colour = match self.occupant[idx].map(|unit| self.units[unit].species) {
+                    Some(Species::GOBLIN) => Some(Colour::Red),
+                    Some(Species::ELF) => Some(Colour::Green),
+                    None if self.grid[idx] == Cell::Wall => Some(Colour::Grey),
+                    None => None,
+                };
+                let mut glyph = Glyph::new(self.symbol_at(idx).chars().next().unwrap(), colour);
+                glyph.active = active.is_some() && self.occupant[idx] == active.map(|(unit, _)| unit);
+                glyph
+            }).collect();
+
+            let notes = row.iter().filter_map(|&idx| self.occupant[idx]).map(|unit| {
+                let character = &self.units[unit];
+                let max_health = self.config.stats_for(character.species).health.max(1);
+                let colour = match 3 * character.health / max_health {
+                    0 => Colour::Red,
+                    1 => Colour::Yellow,
+                    _ => Colour::Green,
+                };
+                (format!("{}({})", self.symbol_at(character.position), character.health), Some(colour))
+            }).collect();
+
+            Line { glyphs, notes }
+        }).collect();
+
+        Frame { caption, lines }
+    }
+
+    fn apply(&mut self, event: &CombatEvent) {
+        match *event {
+            CombatEvent::Move { unit, to, .. } => {
+                let to = self.index(to);
+                self.move_unit(unit, to);
+            },
+            CombatEvent::Attack { target, remaining_health, .. } => {
+                self.units[target].health = remaining_health;
+            },
+            CombatEvent::Death { unit, .. } => {
+                self.remove_unit(unit);
+            },
+        }
+    }
+
+    fn replay(&self, events: &[CombatEvent], rounds: usize) -> Map {
+        let mut map = self.clone();
+        for event in events.iter().filter(|event| event.round() <= rounds) {
+            map.apply(event);
+        }
+        map.rounds = rounds;
+        map
+    }
+
+    fn total_health(&self) -> usize {
+        self.living_units().map(|character| character.health).sum()
+    }
+
+    fn symbol_at(&self, idx: usize) -> String {
+        match self.occupant[idx].map(|unit| self.units[unit].species) {
+            Some(Species::GOBLIN) => "G".to_string(),
+            Some(Species::ELF) => "E".to_string(),
+            None => self.grid[idx].to_string(),
+        }
+    }
+
+    fn row(&self, y: usize) -> Vec<usize> {
+        (0..self.width).map(|x| self.index(Coordinate { x, y })).collect()
+    }
+
+    // Matches the map-with-health layout used in the puzzle examples
+    fn health_report(&self) -> String {
+        let mut report = String::new();
+        for y in 0..self.height {
+            let row = self.row(y);
+            report.extend(row.iter().map(|&idx| self.symbol_at(idx)));
+
+            let healths: Vec<String> = row.iter()
+                .filter_map(|&idx| self.occupant[idx])
+                .map(|unit| format!("{}({})", self.symbol_at(self.units[unit].position), self.units[unit].health))
+                .collect();
+            if !healths.is_empty() {
+                report.push_str("   ");
+                report.push_str(&healths.join(", "));
+            }
+            report.push('\n');
+        }
+        report
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            for idx in self.row(y) {
+                write!(f, "{}", self.symbol_at(idx))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn q1(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    let map: Vec<Vec<char>> = f_contents.lines().map(|x: &str| {
+        x.to_string().chars().collect::<Vec<char>>()
+    }).collect();
+
+    _q1(map).unwrap()
+}
+
+// The battle's event log is kept and replayed as a check that it accounts for every change to the
+// map, including those made in the round the battle ended part way through
+fn _q1(input_grid: Vec<Vec<char>>) -> Result<usize> {
+    let initial = Map::new(input_grid)?;
+    let mut map = initial.clone();
+    map.record_events();
+    let outcome = map.outcome()?;
+
+    let events = map.events.take().unwrap_or_default();
+    if initial.replay(&events, map.rounds + 1).health_report() != map.health_report() {
+        return err!("Replaying the {} recorded events doesn't reach the map the battle ended with", events.len());
+    }
+
+    Ok(outcome)
+}
+
+pub fn q2(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    let map: Vec<Vec<char>> = f_contents.lines().map(|x: &str| {
+        x.to_string().chars().collect::<Vec<char>>()
+    }).collect();
+
+    _q2(map).unwrap()
+}
+
+// Rule changes given to the animation, such as `--initiative elves-first` or `--movement stationary`
+fn config_from_options(options: &[(String, String)]) -> Result<CombatConfig> {
+    let mut config = CombatConfig::default();
+    for (option, value) in options {
+        match (option.as_str(), value.as_str()) {
+            ("--initiative", "reading-order") => config.initiative = Initiative::ReadingOrder,
+            ("--initiative", "elves-first") => config.initiative = Initiative::SpeciesFirst(Species::ELF),
+            ("--initiative", "goblins-first") => config.initiative = Initiative::SpeciesFirst(Species::GOBLIN),
+            ("--movement", "towards-nearest-target") => config.movement = MovementRule::TowardsNearestTarget,
+            ("--movement", "stationary") => config.movement = MovementRule::Stationary,
+            _ => return err!("Cannot use {:?} for {}", value, option),
+        }
+    }
+    Ok(config)
+}
+
+pub fn animate(fname: String, target: Target, options: &[(String, String)]) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    let map: Vec<Vec<char>> = f_contents.lines().map(|x: &str| {
+        x.to_string().chars().collect::<Vec<char>>()
+    }).collect();
+
+    let config = config_from_options(options).unwrap();
+    let mut animator = Animator::new(target).unwrap();
+    Map::with_config(map, config).unwrap().animate(&mut animator).unwrap()
+}
+
+fn flawless_outcome(input_grid: &[Vec<char>], config: CombatConfig) -> Result<Option<usize>> {
+    Map::with_config(input_grid.to_vec(), config.without_elf_losses())?.elfy_outcome()
+}
+
+fn flawless_outcomes(input_grid: &[Vec<char>], config: CombatConfig, attacks: &[usize]) -> Result<Vec<Option<usize>>> {
+    if attacks.len() < 2 {
+        return attacks.iter().map(|&atk| flawless_outcome(input_grid, config.with_elf_attack(atk))).collect();
+    }
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = attacks
+            .iter()
+            .map(|&atk| scope.spawn(move || flawless_outcome(input_grid, config.with_elf_attack(atk)).map_err(|e| e.to_string())))
+            .collect();
+
+        handles.into_iter().map(|handle| Ok(handle.join().map_err(|_| "Battle thread panicked")??)).collect()
+    })
+}
+
+// A higher attack doesn't always help: it changes which units die when, and so who moves where,
+// and can cost an elf that a lower attack would have saved. So every attack is tried in order,
+// a batch at a time in parallel, and the lowest flawless win is taken.
+fn minimum_flawless_attack(input_grid: &[Vec<char>], config: CombatConfig) -> Result<(usize, usize)> {
+    let batch_size = thread::available_parallelism().map(|n| n.get()).unwrap_or(1).max(2);
+    let max_attack = config.goblin.health.max(1);
+
+    let mut lower = config.elf.attack;
+    while lower <= max_attack {
+        let candidates: Vec<usize> = (lower..cmp::min(lower + batch_size, max_attack + 1)).collect();
+        let outcomes = flawless_outcomes(input_grid, config, &candidates)?;
+        if let Some((&atk, &Some(outcome))) = candidates.iter().zip(outcomes.iter()).find(|(_, outcome)| outcome.is_some()) {
+            return Ok((atk, outcome));
+        }
+        lower += candidates.len();
+    }
+
+    err!("Elves cannot win without losses, even killing goblins in one hit")
+}
+
+fn _q2(input_grid: Vec<Vec<char>>) -> Result<usize> {
+    let (_, outcome) = minimum_flawless_attack(&input_grid, CombatConfig::default().with_elf_attack(4))?;
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn q1_test1() {
+        assert_eq!(
+            _q1(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', 'G', '.', '.', '#', 'E', '#'],
+                vec!['#', 'E', '#', 'E', '.', 'E', '#'],
+                vec!['#', 'G', '.', '#', '#', '.', '#'],
+                vec!['#', '.', '.', '.', '#', 'E', '#'],
+                vec!['#', '.', '.', '.', 'E', '.', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            36334
+        );
+    }
+
+    #[test]
+    fn q1_test2() {
+        assert_eq!(
+            _q1(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', 'E', '.', '.', 'E', 'G', '#'],
+                vec!['#', '.', '#', 'G', '.', 'E', '#'],
+                vec!['#', 'E', '.', '#', '#', 'E', '#'],
+                vec!['#', 'G', '.', '.', '#', '.', '#'],
+                vec!['#', '.', '.', 'E', '#', '.', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            39514
+        );
+    }
+
+    #[test]
+    fn q1_test3() {
+        assert_eq!(
+            _q1(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', 'E', '.', 'G', '#', '.', '#'],
+                vec!['#', '.', '#', 'G', '.', '.', '#'],
+                vec!['#', 'G', '.', '#', '.', 'G', '#'],
+                vec!['#', 'G', '.', '.', '#', '.', '#'],
+                vec!['#', '.', '.', '.', 'E', '.', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            27755
+        );
+    }
+
+    #[test]
+    fn q1_test4() {
+        assert_eq!(
+            _q1(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', '.', 'E', '.', '.', '.', '#'],
+                vec!['#', '.', '#', '.', '.', 'G', '#'],
+                vec!['#', '.', '#', '#', '#', '.', '#'],
+                vec!['#', 'E', '#', 'G', '#', 'G', '#'],
+                vec!['#', '.', '.', '.', '#', 'G', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            28944
+        );
+    }
+
+    #[test]
+    fn q1_test5() {
+        assert_eq!(
+            _q1(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', 'G', '.', '.', '.', '.', '.', '.', '#'],
+                vec!['#', '.', 'E', '.', '#', '.', '.', '.', '#'],
+                vec!['#', '.', '.', '#', '#', '.', '.', 'G', '#'],
+                vec!['#', '.', '.', '.', '#', '#', '.', '.', '#'],
+                vec!['#', '.', '.', '.', '#', '.', '.', '.', '#'],
+                vec!['#', '.', 'G', '.', '.', '.', 'G', '.', '#'],
+                vec!['#', '.', '.', '.', '.', '.', 'G', '.', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            18740
+        );
+    }
+
+    #[test]
+    fn q1_test6() {
+        assert_eq!(
+            _q1(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', '.', 'G', '.', '.', '.', '#'],
+                vec!['#', '.', '.', '.', 'E', 'G', '#'],
+                vec!['#', '.', '#', '.', '#', 'G', '#'],
+                vec!['#', '.', '.', 'G', '#', 'E', '#'],
+                vec!['#', '.', '.', '.', '.', '.', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            27730
+        );
+    }
+
+    fn example_grid() -> Vec<Vec<char>> {
+        vec![
+            vec!['#', '#', '#', '#', '#', '#', '#'],
+            vec!['#', '.', 'G', '.', '.', '.', '#'],
+            vec!['#', '.', '.', '.', 'E', 'G', '#'],
+            vec!['#', '.', '#', '.', '#', 'G', '#'],
+            vec!['#', '.', '.', 'G', '#', 'E', '#'],
+            vec!['#', '.', '.', '.', '.', '.', '#'],
+            vec!['#', '#', '#', '#', '#', '#', '#'],
+        ]
+    }
+
+    #[test]
+    fn event_log_replays_published_rounds() {
+        let initial = Map::new(example_grid()).unwrap();
+        let mut map = initial.clone();
+        map.record_events();
+        assert_eq!(map.outcome().unwrap(), 27730);
+
+        let events = map.events.clone().unwrap();
+        assert_eq!(
+            initial.replay(&events, 1).health_report(),
+            "#######\n\
+             #..G..#   G(200)\n\
+             #...EG#   E(197), G(197)\n\
+             #.#G#G#   G(200), G(197)\n\
+             #...#E#   E(197)\n\
+             #.....#\n\
+             #######\n"
+        );
+        assert_eq!(
+            initial.replay(&events, 47).health_report(),
+            "#######\n\
+             #G....#   G(200)\n\
+             #.G...#   G(131)\n\
+             #.#.#G#   G(59)\n\
+             #...#.#\n\
+             #....G#   G(200)\n\
+             #######\n"
+        );
+        assert_eq!(initial.replay(&events, 47).health_report(), map.health_report());
+        assert_eq!(
+            events.iter().filter(|event| matches!(event, CombatEvent::Death { .. })).count(),
+            2
+        );
+    }
+
+    #[test]
+    fn stationary_units_stalemate() {
+        let config = CombatConfig { movement: MovementRule::Stationary, ..CombatConfig::default() };
+        assert!(Map::with_config(example_grid(), config).unwrap().outcome().is_err());
+    }
+
+    #[test]
+    fn config_from_options_tests() {
+        let options = [
+            ("--initiative".to_string(), "goblins-first".to_string()),
+            ("--movement".to_string(), "stationary".to_string()),
+        ];
+        let config = config_from_options(&options).unwrap();
+        assert_eq!(config.initiative, Initiative::SpeciesFirst(Species::GOBLIN));
+        assert_eq!(config.movement, MovementRule::Stationary);
+
+        assert_eq!(config_from_options(&[]).unwrap(), CombatConfig::default());
+        assert!(config_from_options(&[("--initiative".to_string(), "random".to_string())]).is_err());
+    }
+
+    #[test]
+    fn config_changes_outcome() {
+        let config = CombatConfig {
+            goblin: SpeciesStats { attack: 3, health: 10 },
+            initiative: Initiative::SpeciesFirst(Species::ELF),
+            ..CombatConfig::default()
+        };
+        let mut map = Map::with_config(example_grid(), config).unwrap();
+        map.outcome().unwrap();
+        assert!(!map.any_elves_lost());
+    }
+
+    #[test]
+    fn battle_aborts_on_first_elf_death() {
+        let mut full = Map::new(example_grid()).unwrap();
+        full.outcome().unwrap();
+
+        let mut aborted = Map::with_config(example_grid(), CombatConfig::default().without_elf_losses()).unwrap();
+        assert_eq!(aborted.elfy_outcome().unwrap(), None);
+        assert!(aborted.rounds < full.rounds);
+    }
+
+    #[test]
+    fn minimum_attack_matches_linear_scan() {
+        let config = CombatConfig::default().with_elf_attack(4);
+        let linear = (4..201)
+            .find(|&atk| flawless_outcome(&example_grid(), config.with_elf_attack(atk)).unwrap().is_some())
+            .unwrap();
+
+        assert_eq!(minimum_flawless_attack(&example_grid(), config).unwrap(), (linear, 4988));
+        assert_eq!(linear, 15);
+    }
+
+    #[test]
+    fn minimum_attack_is_not_assumed_monotonic() {
+        let grid: Vec<Vec<char>> = [
+            "#######",
+            "#.#..E#",
+            "#..E..#",
+            "#.GG.##",
+            "#G.G#.#",
+            "#######",
+        ].iter().map(|row| row.chars().collect()).collect();
+        let config = CombatConfig::default().with_elf_attack(4);
+
+        assert!(flawless_outcome(&grid, config.with_elf_attack(7)).unwrap().is_none());
+        assert!(flawless_outcome(&grid, config.with_elf_attack(8)).unwrap().is_some());
+        assert!(flawless_outcome(&grid, config.with_elf_attack(10)).unwrap().is_none());
+        assert_eq!(minimum_flawless_attack(&grid, config).unwrap().0, 8);
+    }
+
+    #[test]
+    fn stalemates_are_not_flawless() {
+        let duel: Vec<Vec<char>> = ["#######", "#E...G#", "#######"].iter().map(|row| row.chars().collect()).collect();
+        let config = CombatConfig { max_rounds: Some(20), ..CombatConfig::default() }.with_elf_attack(4);
+
+        // Weaker elves are still fighting when the rounds run out, so the search carries on past them
+        assert_eq!(flawless_outcome(&duel, config).unwrap(), None);
+        assert!(Map::with_config(duel.clone(), config).unwrap().outcome().is_err());
+        let (attack, _) = minimum_flawless_attack(&duel, config).unwrap();
+        assert_eq!(attack, 12);
+        assert_eq!(flawless_outcome(&duel, config.with_elf_attack(attack - 1)).unwrap(), None);
+
+        // A goblin walled off from every elf holds out at any attack
+        let walled: Vec<Vec<char>> = ["#########", "#E..G#G.#", "#########"].iter().map(|row| row.chars().collect()).collect();
+        let config = CombatConfig::default().with_elf_attack(4);
+        assert_eq!(flawless_outcome(&walled, config).unwrap(), None);
+        assert!(minimum_flawless_attack(&walled, config).unwrap_err().to_string().contains("cannot win"));
+    }
+
+    // Deterministic pseudo-random 100x100 cave with a few hundred units
+    fn large_map() -> Map {
+        let mut seed: u64 = 15;
+        let mut next = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) % 100
+        };
+        let input_grid: Vec<Vec<char>> = (0..100).map(|y| {
+            (0..100).map(|x| {
+                if x == 0 || y == 0 || x == 99 || y == 99 {
+                    return '#';
+                }
+                match next() {
+                    0..=4 => '#',
+                    5 => 'G',
+                    6 => 'E',
+                    _ => '.',
+                }
+            }).collect()
+        }).collect();
+
+        Map::new(input_grid).unwrap()
+    }
+
+    #[test]
+    fn large_map_simulates() {
+        let mut map = large_map();
+        assert!(map.units.len() > 150);
+        map.outcome().unwrap();
+        assert!(map.living_units().all(|c| c.species == map.living_units().next().unwrap().species));
+    }
+
+    // Only meaningful with optimisations on, so run it with `cargo test --release`
+    #[test]
+    #[cfg_attr(debug_assertions, ignore)]
+    fn large_map_simulates_quickly() {
+        let mut map = large_map();
+        let start = Instant::now();
+        map.outcome().unwrap();
+        assert!(start.elapsed() < Duration::from_secs(5), "took {:?}", start.elapsed());
+    }
+
+    #[test]
+    fn animation_writes_a_frame_per_turn() {
+        let dir = ::std::env::temp_dir().join(format!("aoc_day15_animation_{}", ::std::process::id()));
+        let mut animator = Animator::new(Target::Directory(dir.clone())).unwrap();
+
+        let mut map = Map::new(example_grid()).unwrap();
+        assert_eq!(map.animate(&mut animator).unwrap(), 27730);
+        assert!(animator.frames_shown() > 47);
+
+        let last = ::std::fs::read_to_string(dir.join(format!("{:05}.txt", animator.frames_shown() - 1))).unwrap();
+        assert_eq!(last, format!("After 47 full rounds\n{}", map.health_report()));
+        let captions: Vec<String> = (1..animator.frames_shown())
+            .map(|frame| ::std::fs::read_to_string(dir.join(format!("{:05}.txt", frame))).unwrap().lines().next().unwrap().to_string())
+            .collect();
+        assert_eq!(captions[0], "Round 1: Goblin 0 moved");
+        assert_eq!(captions[1], "Round 1: Elf 1 attacked Goblin 2");
+        assert_eq!(captions[6], "Round 2: Goblin 0 moved and attacked Elf 1");
+        assert!(captions.iter().any(|caption| caption.contains("killed Elf")));
+        ::std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn q2_test1() {
+        assert_eq!(
+            _q2(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', '.', 'G', '.', '.', '.', '#'],
+                vec!['#', '.', '.', '.', 'E', 'G', '#'],
+                vec!['#', '.', '#', '.', '#', 'G', '#'],
+                vec!['#', '.', '.', 'G', '#', 'E', '#'],
+                vec!['#', '.', '.', '.', '.', '.', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            4988
+        );
+    }
+
+    #[test]
+    fn q2_test2() {
+        assert_eq!(
+            _q2(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', 'E', '.', '.', 'E', 'G', '#'],
+                vec!['#', '.', '#', 'G', '.', 'E', '#'],
+                vec!['#', 'E', '.', '#', '#', 'E', '#'],
+                vec!['#', 'G', '.', '.', '#', '.', '#'],
+                vec!['#', '.', '.', 'E', '#', '.', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            31284
+        );
+    }
+
+    #[test]
+    fn q2_test3() {
+        assert_eq!(
+            _q2(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', 'E', '.', 'G', '#', '.', '#'],
+                vec!['#', '.', '#', 'G', '.', '.', '#'],
+                vec!['#', 'G', '.', '#', '.', 'G', '#'],
+                vec!['#', 'G', '.', '.', '#', '.', '#'],
+                vec!['#', '.', '.', '.', 'E', '.', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            3478
+        );
+    }
+
+    #[test]
+    fn q2_test4() {
+        assert_eq!(
+            _q2(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', '.', 'E', '.', '.', '.', '#'],
+                vec!['#', '.', '#', '.', '.', 'G', '#'],
+                vec!['#', '.', '#', '#', '#', '.', '#'],
+                vec!['#', 'E', '#', 'G', '#', 'G', '#'],
+                vec!['#', '.', '.', '.', '#', 'G', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            6474
+        );
+    }
+
+    #[test]
+    fn q2_test5() {
+        assert_eq!(
+            _q2(vec![
+                vec!['#', '#', '#', '#', '#', '#', '#', '#', '#'],
+                vec!['#', 'G', '.', '.', '.', '.', '.', '.', '#'],
+                vec!['#', '.', 'E', '.', '#', '.', '.', '.', '#'],
+                vec!['#', '.', '.', '#', '#', '.', '.', 'G', '#'],
+                vec!['#', '.', '.', '.', '#', '#', '.', '.', '#'],
+                vec!['#', '.', '.', '.', '#', '.', '.', '.', '#'],
+                vec!['#', '.', 'G', '.', '.', '.', 'G', '.', '#'],
+                vec!['#', '.', '.', '.', '.', '.', 'G', '.', '#'],
+                vec!['#', '#', '#', '#', '#', '#', '#', '#', '#'],
+            ]).unwrap(),
+            1140
+        );
+    }
+}
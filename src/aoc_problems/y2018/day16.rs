@@ -1,25 +1,16 @@
 use std::error::Error;
 use std::fs::File;
-use std::io;
 use std::io::prelude::*;
 use std::result;
 
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
-type Result<T> = result::Result<T, Box<dyn Error>>;
-
-fn pause() {
-    let mut stdin = io::stdin();
-    let mut stdout = io::stdout();
-
-    // We want the cursor to stay at the end of the line, so we print without a newline and flush manually.
-    write!(stdout, "Press any key to continue...").unwrap();
-    stdout.flush().unwrap();
-
-    // Read a single byte and discard
-    let _ = stdin.read(&mut [0u8]).unwrap();
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
 }
 
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 struct InstructionSet {
     input_1: usize,
@@ -239,7 +230,7 @@ impl Sample {
     }
 }
 
-fn generate_opcode_number_map(opcode_str_map: BTreeMap<String, &Opcode>, samples: Vec<Sample>) -> HashMap<usize, &Opcode> {
+fn generate_opcode_number_map(opcode_str_map: BTreeMap<String, &Opcode>, samples: Vec<Sample>) -> Result<HashMap<usize, &Opcode>> {
     let mut opcode_possibilities: BTreeMap<usize, HashSet<String>> = (0..16)
         .map(|n| (n, opcode_str_map.keys().map(|s| s.clone()).collect()))
         .collect();
@@ -250,18 +241,15 @@ fn generate_opcode_number_map(opcode_str_map: BTreeMap<String, &Opcode>, samples
             .filter(|(_, opcode_fn)| opcode_fn(sample.instruction.instruction_set, sample.before) == sample.after)
             .map(|(s, _)| s.clone())
             .collect();
-        println!("Number of possible opcodes = {}", possible_opcodes.len());
 
         let previous_guesses = match opcode_possibilities.get_mut(&sample.instruction.opcode_num) {
             Some(set) => set,
-            None => panic!("{} is not in the hash map for opcode numbers", sample.instruction.opcode_num)
+            None => return err!("{} is not in the hash map for opcode numbers", sample.instruction.opcode_num),
         };
 
         *previous_guesses = previous_guesses.intersection(&possible_opcodes).cloned().collect();
     }
 
-    println!("Possibilities now {:?}", opcode_possibilities);
-
     let mut number_translation_map: HashMap<usize, &Opcode> = HashMap::new();
 
     while opcode_possibilities.len() > 0 {
@@ -273,12 +261,10 @@ fn generate_opcode_number_map(opcode_str_map: BTreeMap<String, &Opcode>, samples
             .collect();
 
         if decided_opcodes.len() == 0 {
-            println!("Cannot decipher which opcodes are which!: {:?}", opcode_possibilities);
-            panic!("Cannot decipher which opcodes are which!");
+            return err!("Cannot decipher which opcodes are which: {:?}", opcode_possibilities);
         }
 
         while let Some((idx, opcode_str, opcode_fn)) = decided_opcodes.pop_front() {
-            println!("{} corresponds to {}", idx, opcode_str);
             number_translation_map.insert(idx, opcode_fn);
             opcode_possibilities.remove(&idx);
             for opcode_set in opcode_possibilities.values_mut() {
@@ -287,19 +273,15 @@ fn generate_opcode_number_map(opcode_str_map: BTreeMap<String, &Opcode>, samples
         }
     }
 
-    number_translation_map
+    Ok(number_translation_map)
 }
 
-pub fn q1(fname: String) -> usize {
-    let mut f = File::open(fname).expect("File not found");
-    let mut f_contents = String::new();
-
-    f.read_to_string(&mut f_contents).expect("Couldn't find file");
-    let input_list: Vec<String> = f_contents.lines().map(|x: &str| {
+fn parse_samples(contents: &str) -> Vec<Sample> {
+    let input_list: Vec<String> = contents.lines().map(|x: &str| {
         x.to_string()
     }).collect();
 
-    let samples: Vec<Sample> = input_list
+    input_list
         .windows(3)
         .filter_map(|strings| {
             match strings[0].starts_with("Before: ") && strings[2].starts_with("After: ") {
@@ -332,92 +314,56 @@ pub fn q1(fname: String) -> usize {
                 ))
             }
         })
-        .collect();
-
-    println!("Number of input samples = {}", samples.len());
+        .collect()
+}
 
-    let opcodes = create_opcode_string_map();
+// One instruction a line: an opcode number, two inputs and the register to write to
+fn parse_program(contents: &str) -> Result<Vec<Instruction>> {
+    contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()).map(|(line_idx, line)| {
+        let numbers: Vec<usize> = match line.split_whitespace().map(|s| s.parse()).collect() {
+            Ok(numbers) => numbers,
+            Err(_) => return err!("Line {}: cannot read an instruction from {:?}", line_idx + 1, line),
+        };
+        if numbers.len() != 4 || numbers[3] >= 4 {
+            return err!("Line {}: {:?} is not an opcode, two inputs and an output register", line_idx + 1, line);
+        }
 
-    samples.iter().filter(|sample| sample.opcode_matches(&opcodes) >= 3).count()
+        Ok(Instruction::new(numbers))
+    }).collect()
 }
 
-pub fn q2(fname: String) -> usize {
+fn read_file(fname: String) -> String {
     let mut f = File::open(fname).expect("File not found");
     let mut f_contents = String::new();
-
     f.read_to_string(&mut f_contents).expect("Couldn't find file");
-    let input_list: Vec<String> = f_contents.lines().map(|x: &str| {
-        x.to_string()
-    }).collect();
-
-    let samples: Vec<Sample> = input_list
-        .windows(3)
-        .filter_map(|strings| {
-            match strings[0].starts_with("Before: ") && strings[2].starts_with("After: ") {
-                false => None,
-                true => Some(Sample::new(
-                    new_register(
-                        strings[0]
-                            .replace("Before:", "")
-                            .trim_matches(|c: char| c == '[' || c == ']' || c.is_whitespace())
-                            .split(", ")
-                            .map(|s| s.parse().unwrap())
-                            .collect()
-                    ),
-                    Instruction::new(
-                        strings[1]
-                            .trim()
-                            .split(' ')
-                            .into_iter()
-                            .map(|s| s.parse().unwrap())
-                            .collect()
-                    ),
-                    new_register(
-                        strings[2]
-                            .replace("After:", "")
-                            .trim_matches(|c: char| c == '[' || c == ']' || c.is_whitespace())
-                            .split(", ")
-                            .map(|s| s.parse().unwrap())
-                            .collect()
-                    ),
-                ))
-            }
-        })
-        .collect();
-
-    println!("Number of input samples = {}", samples.len());
+    f_contents
+}
 
+pub fn q1(fname: String) -> usize {
+    let samples = parse_samples(&read_file(fname));
     let opcodes = create_opcode_string_map();
-    let opcode_num_map = generate_opcode_number_map(opcodes, samples);
 
-    let mut instruction_f = File::open("./inputs/day16_2.txt").expect("File not found");
-    let mut instruction_f_contents = String::new();
-
-    instruction_f.read_to_string(&mut instruction_f_contents).expect("Couldn't find file");
-    let instruction_list: Vec<String> = instruction_f_contents.lines().map(|x: &str| {
-        x.to_string()
-    }).collect();
+    samples.iter().filter(|sample| sample.opcode_matches(&opcodes) >= 3).count()
+}
 
-    let instruction_list: Vec<Instruction> = instruction_list.into_iter().map(|l| {
-        Instruction::new(
-            l.trim()
-                .split(' ')
-                .into_iter()
-                .map(|s| s.parse().unwrap())
-                .collect()
-        )
-    }).collect();
+// The samples come from the day's input, and the program to run from the input saved for part 2
+pub fn q2(fname: String, program_fname: String) -> usize {
+    _q2(&read_file(fname), &read_file(program_fname)).unwrap()
+}
 
-    println!("Instruction count = {}", instruction_list.len());
+fn _q2(samples: &str, program: &str) -> Result<usize> {
+    let opcode_num_map = generate_opcode_number_map(create_opcode_string_map(), parse_samples(samples))?;
 
     let mut register: Register = [0, 0, 0, 0];
-    for instruction in instruction_list {
-        register = opcode_num_map.get(&instruction.opcode_num).unwrap()(instruction.instruction_set, register);
+    for instruction in parse_program(program)? {
+        let opcode = match opcode_num_map.get(&instruction.opcode_num) {
+            Some(opcode) => opcode,
+            None => return err!("No opcode is numbered {}", instruction.opcode_num),
+        };
+        register = opcode(instruction.instruction_set, register);
     }
 
-    println!("Register is now {:?}", register);
-
-    unimplemented!();
+    Ok(register[0])
 }
 
 #[cfg(test)]
@@ -466,4 +412,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn q2_input_tests() {
+        assert_eq!(parse_program("9 2 1 2\n\n0 0 0 3\n").unwrap().len(), 2);
+        assert!(parse_program("9 2 1").is_err());
+        assert!(parse_program("9 2 1 4").is_err());
+        assert!(parse_program("nine 2 1 2").is_err());
+
+        // Without samples no opcode number can be matched to an opcode
+        assert!(_q2("", "9 2 1 2").is_err());
+    }
 }
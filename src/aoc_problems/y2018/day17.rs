@@ -0,0 +1,553 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::prelude::*;
+use std::ops::RangeInclusive;
+use std::result;
+use std::str::FromStr;
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use itertools::MinMaxResult::{MinMax, NoElements, OneElement};
+
+use aoc_problems::animation::{Animator, Colour, Frame, Glyph, Line, Target};
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+struct Coordinate {
+    x: usize,
+    y: usize
+}
+
+impl Coordinate {
+    fn square_below(self) -> Coordinate {
+        Coordinate { y: self.y + 1, ..self }
+    }
+
+    fn square_to_the(self, direction: Direction) -> Coordinate {
+        match direction {
+            Direction::Left => Coordinate { x: self.x - 1, ..self },
+            Direction::Right => Coordinate { x: self.x + 1, ..self },
+        }
+    }
+}
+
+impl Ord for Coordinate {
+    fn cmp(&self, other: &Coordinate) -> cmp::Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+impl PartialOrd for Coordinate {
+    fn partial_cmp(&self, other: &Coordinate) -> Option<cmp::Ordering> {
+        Some((self.y, self.x).cmp(&(other.y, other.x)))
+    }
+}
+
+impl fmt::Debug for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+enum WaterType {
+    Still,
+    Flowing
+}
+
+impl fmt::Display for WaterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WaterType::Still => write!(f, "~"),
+            WaterType::Flowing => write!(f, "|"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+enum Material {
+    Clay,
+    Sand,
+    Spring,
+    Water(WaterType)
+}
+
+impl Material {
+    fn can_stay_on(self) -> bool {
+        matches!(self, Material::Clay | Material::Water(WaterType::Still))
+    }
+
+    fn colour(self) -> Option<Colour> {
+        match self {
+            Material::Clay => Some(Colour::Yellow),
+            Material::Sand => None,
+            Material::Spring => Some(Colour::Magenta),
+            Material::Water(WaterType::Flowing) => Some(Colour::Cyan),
+            Material::Water(WaterType::Still) => Some(Colour::Blue),
+        }
+    }
+}
+
+impl fmt::Display for Material {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Material::Clay => write!(f, "#"),
+            Material::Sand => write!(f, "."),
+            Material::Spring => write!(f, "+"),
+            Material::Water(water_type) => write!(f, "{}", water_type),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ClayVein {
+    xs: RangeInclusive<usize>,
+    ys: RangeInclusive<usize>
+}
+
+impl ClayVein {
+    fn squares(&self) -> impl Iterator<Item = Coordinate> {
+        let xs = self.xs.clone();
+        self.ys.clone().flat_map(move |y| xs.clone().map(move |x| Coordinate { x, y }))
+    }
+}
+
+// Either a single value, `7`, or an inclusive range in either order, `3..10` or `10..3`
+fn parse_range(text: &str) -> Result<RangeInclusive<usize>> {
+    let (first, last) = match text.find("..") {
+        Some(idx) => (&text[..idx], &text[idx + 2..]),
+        None => (text, text),
+    };
+
+    let parse = |number: &str| -> Result<usize> {
+        match number.trim().parse() {
+            Ok(n) => Ok(n),
+            Err(_) => err!("Cannot read {:?} as a coordinate", number),
+        }
+    };
+    let (first, last) = (parse(first)?, parse(last)?);
+
+    Ok(cmp::min(first, last)..=cmp::max(first, last))
+}
+
+// Accepts `x=495, y=2..7`, `y=7, x=495..501` or a single square such as `x=5, y=7`
+impl FromStr for ClayVein {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<ClayVein> {
+        let mut xs: Option<RangeInclusive<usize>> = None;
+        let mut ys: Option<RangeInclusive<usize>> = None;
+        for part in s.split(',') {
+            let (axis, range) = match part.find('=') {
+                Some(idx) => (part[..idx].trim(), &part[idx + 1..]),
+                None => return err!("Expected `x=...` or `y=...` but found {:?}", part.trim()),
+            };
+            let slot = match axis {
+                "x" => &mut xs,
+                "y" => &mut ys,
+                _ => return err!("Unknown axis {:?}", axis),
+            };
+            if slot.is_some() {
+                return err!("{} is given more than once", axis);
+            }
+            *slot = Some(parse_range(range)?);
+        }
+
+        match (xs, ys) {
+            (Some(xs), Some(ys)) => Ok(ClayVein { xs, ys }),
+            (None, _) => err!("No x coordinate given"),
+            (_, None) => err!("No y coordinate given"),
+        }
+    }
+}
+
+fn parse_veins(scan: &[String]) -> Result<Vec<ClayVein>> {
+    scan.iter()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| match line.parse() {
+            Ok(vein) => Ok(vein),
+            Err(e) => err!("Line {}: {}", idx + 1, e),
+        })
+        .collect()
+}
+
+// The puzzle's spring
+const DEFAULT_SPRING: Coordinate = Coordinate { x: 500, y: 0 };
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+struct WaterCounts {
+    flowing: usize,
+    settled: usize
+}
+
+impl WaterCounts {
+    fn total(&self) -> usize {
+        self.flowing + self.settled
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Task {
+    // Water pours down from this square until it lands on something
+    Fall(Coordinate),
+    // Water has landed on this square and runs sideways
+    Spread(Coordinate)
+}
+
+// Only clay, springs and water are stored; every other square is sand. Water is only counted
+// in the rows covered by the scan, from the shallowest to the deepest clay.
+struct Underground {
+    min_coord: Coordinate,
+    max_coord: Coordinate,
+    material_grid: HashMap<Coordinate, Material>,
+    springs: Vec<Coordinate>
+}
+
+impl Underground {
+    fn new(veins: &[ClayVein], springs: &[Coordinate]) -> Result<Underground> {
+        let mut material_grid: HashMap<Coordinate, Material> = HashMap::new();
+        for vein in veins {
+            for c in vein.squares() {
+                material_grid.insert(c, Material::Clay);
+            }
+        }
+
+        let (min_x, max_x) = match veins.iter().flat_map(|vein| vec![*vein.xs.start(), *vein.xs.end()]).minmax() {
+            MinMax(min, max) => (min, max),
+            OneElement(x) => (x, x),
+            NoElements => return err!("There is no clay to scan"),
+        };
+        let (min_y, max_y) = match veins.iter().flat_map(|vein| vec![*vein.ys.start(), *vein.ys.end()]).minmax() {
+            MinMax(min, max) => (min, max),
+            OneElement(y) => (y, y),
+            NoElements => return err!("There is no clay to scan"),
+        };
+
+        // Water can run one square past the outermost clay
+        let mut min_coord = Coordinate { x: min_x.saturating_sub(1), y: min_y };
+        let mut max_coord = Coordinate { x: max_x + 1, y: max_y };
+        for spring in springs {
+            min_coord.x = cmp::min(min_coord.x, spring.x.saturating_sub(1));
+            max_coord.x = cmp::max(max_coord.x, spring.x + 1);
+            material_grid.insert(*spring, Material::Spring);
+        }
+
+        Ok(Underground {
+            material_grid,
+            min_coord,
+            max_coord,
+            springs: springs.to_vec()
+        })
+    }
+
+    fn material_at(&self, c: Coordinate) -> Material {
+        self.material_grid.get(&c).cloned().unwrap_or(Material::Sand)
+    }
+
+    fn wet(&mut self, c: Coordinate, water_type: WaterType) {
+        if self.material_at(c) != Material::Spring {
+            self.material_grid.insert(c, Material::Water(water_type));
+        }
+    }
+
+    fn flow(&mut self) -> Result<()> {
+        self.flow_with(|_| Ok(()))
+    }
+
+    // Each square is poured into at most once and each row segment is spread across a bounded
+    // number of times, so this is linear in the amount of water rather than a fixpoint loop
+    fn flow_with<F: FnMut(&Underground) -> Result<()>>(&mut self, mut after_step: F) -> Result<()> {
+        let mut tasks: Vec<Task> = self.springs.iter().map(|&spring| Task::Fall(spring)).collect();
+        while let Some(task) = tasks.pop() {
+            match task {
+                Task::Fall(c) => self.fall(c, &mut tasks),
+                Task::Spread(c) => self.spread(c, &mut tasks),
+            }
+            after_step(self)?;
+        }
+        Ok(())
+    }
+
+    fn fall(&mut self, from: Coordinate, tasks: &mut Vec<Task>) {
+        let mut c = from;
+        while c.y < self.max_coord.y {
+            let below = c.square_below();
+            match self.material_at(below) {
+                Material::Sand => {
+                    self.wet(below, WaterType::Flowing);
+                    c = below;
+                },
+                Material::Clay | Material::Water(WaterType::Still) => {
+                    tasks.push(Task::Spread(c));
+                    return;
+                },
+                // Joins water that is already on its way
+                Material::Water(WaterType::Flowing) | Material::Spring => return,
+            }
+        }
+    }
+
+    // Runs sideways until hitting clay or finding nothing to stand on. Returns the last square
+    // reached and whether it was stopped by clay.
+    fn scan(&self, from: Coordinate, direction: Direction) -> (Coordinate, bool) {
+        let mut c = from;
+        loop {
+            if !self.material_at(c.square_below()).can_stay_on() {
+                return (c, false);
+            }
+            let next = c.square_to_the(direction);
+            if self.material_at(next) == Material::Clay {
+                return (c, true);
+            }
+            c = next;
+        }
+    }
+
+    fn spread(&mut self, from: Coordinate, tasks: &mut Vec<Task>) {
+        if self.material_at(from) == Material::Water(WaterType::Still) {
+            return;
+        }
+
+        let (left, left_walled) = self.scan(from, Direction::Left);
+        let (right, right_walled) = self.scan(from, Direction::Right);
+        let row = (left.x..=right.x).map(|x| Coordinate { x, y: from.y });
+
+        if left_walled && right_walled {
+            for c in row {
+                self.wet(c, WaterType::Still);
+            }
+
+            // Whatever was pouring onto this row now runs across the top of it, once per run
+            if from.y == 0 {
+                return;
+            }
+            let mut run_started = false;
+            for x in left.x..=right.x {
+                let above = Coordinate { x, y: from.y - 1 };
+                let pouring = matches!(self.material_at(above), Material::Water(WaterType::Flowing) | Material::Spring);
+                if pouring && !run_started {
+                    tasks.push(Task::Spread(above));
+                }
+                run_started = pouring;
+            }
+        } else {
+            for c in row {
+                self.wet(c, WaterType::Flowing);
+            }
+            if !left_walled {
+                tasks.push(Task::Fall(left));
+            }
+            if !right_walled {
+                tasks.push(Task::Fall(right));
+            }
+        }
+    }
+
+    fn frame(&self, caption: String) -> Frame {
+        let lines = (0..=self.max_coord.y).map(|y| {
+            let glyphs = (self.min_coord.x..=self.max_coord.x).map(|x| {
+                let material = self.material_at(Coordinate { x, y });
+                Glyph::new(material.to_string().chars().next().unwrap(), material.colour())
+            }).collect();
+            Line { glyphs, notes: Vec::new() }
+        }).collect();
+
+        Frame { caption, lines }
+    }
+
+    fn scan_rows(&self) -> RangeInclusive<usize> {
+        self.min_coord.y..=self.max_coord.y
+    }
+
+    fn water_counts(&self) -> WaterCounts {
+        self.water_counts_in(self.scan_rows())
+    }
+
+    fn water_counts_in(&self, rows: RangeInclusive<usize>) -> WaterCounts {
+        let mut counts = WaterCounts::default();
+        for (c, material) in &self.material_grid {
+            if !rows.contains(&c.y) {
+                continue;
+            }
+            match material {
+                Material::Water(WaterType::Flowing) => counts.flowing += 1,
+                Material::Water(WaterType::Still) => counts.settled += 1,
+                _ => {},
+            }
+        }
+        counts
+    }
+}
+
+impl fmt::Display for Underground {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..=self.max_coord.y {
+            for x in self.min_coord.x..=self.max_coord.x {
+                write!(f, "{}", self.material_at(Coordinate { x, y }))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn read_sand_locations(fname: String) -> Vec<String> {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents.lines().map(|x: &str| {
+        x.trim().to_string()
+    }).collect()
+}
+
+pub fn q1(fname: String) -> usize {
+    _q1(read_sand_locations(fname)).unwrap()
+}
+
+fn _q1(sand_locations: Vec<String>) -> Result<usize> {
+    let mut underground = Underground::new(&parse_veins(&sand_locations)?, &[DEFAULT_SPRING])?;
+    underground.flow()?;
+
+    Ok(underground.water_counts().total())
+}
+
+pub fn q2(fname: String) -> usize {
+    _q2(read_sand_locations(fname)).unwrap()
+}
+
+fn _q2(sand_locations: Vec<String>) -> Result<usize> {
+    let mut underground = Underground::new(&parse_veins(&sand_locations)?, &[DEFAULT_SPRING])?;
+    underground.flow()?;
+
+    Ok(underground.water_counts().settled)
+}
+
+pub fn animate(fname: String, target: Target) -> usize {
+    _animate(read_sand_locations(fname), target).unwrap()
+}
+
+fn _animate(sand_locations: Vec<String>, target: Target) -> Result<usize> {
+    let mut animator = Animator::new(target)?;
+    let mut underground = Underground::new(&parse_veins(&sand_locations)?, &[DEFAULT_SPRING])?;
+
+    animator.show(underground.frame("Before the spring overflows".to_string()))?;
+    underground.flow_with(|underground| {
+        // The first frame shows the ground before any water has flowed
+        let counts = underground.water_counts();
+        let caption = format!("Step {}: {} flowing, {} settled", animator.frames_shown(), counts.flowing, counts.settled);
+        Ok(animator.show(underground.frame(caption))?)
+    })?;
+
+    Ok(underground.water_counts().total())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<String> {
+        vec![
+            "x=495, y=2..7".to_string(),
+            "y=7, x=495..501".to_string(),
+            "x=501, y=3..7".to_string(),
+            "x=498, y=2..4".to_string(),
+            "x=506, y=1..2".to_string(),
+            "x=498, y=10..13".to_string(),
+            "x=504, y=10..13".to_string(),
+            "y=13, x=498..504".to_string(),
+        ]
+    }
+
+    #[test]
+    fn q1_test() {
+        assert_eq!(_q1(example()).unwrap(), 57);
+    }
+
+    #[test]
+    fn animation_test() {
+        let dir = ::std::env::temp_dir().join(format!("aoc_day17_animation_{}", ::std::process::id()));
+        let water_count = _animate(example(), Target::Directory(dir.clone())).unwrap();
+        assert_eq!(water_count, 57);
+
+        let mut frames: Vec<_> = ::std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+        frames.sort();
+        let caption = |frame: &::std::path::PathBuf| ::std::fs::read_to_string(frame).unwrap().lines().next().unwrap().to_string();
+        assert_eq!(caption(&frames[0]), "Before the spring overflows");
+        assert!(caption(&frames[1]).starts_with("Step 1: "));
+        assert!(caption(frames.last().unwrap()).starts_with(&format!("Step {}: ", frames.len() - 1)));
+        let last = ::std::fs::read_to_string(frames.last().unwrap()).unwrap();
+        assert!(last.ends_with(
+            "...|||||||||..\n\
+             ...|#~~~~~#|..\n\
+             ...|#~~~~~#|..\n\
+             ...|#~~~~~#|..\n\
+             ...|#######|..\n"
+        ));
+        ::std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn water_counts_test() {
+        let mut underground = Underground::new(&parse_veins(&example()).unwrap(), &[DEFAULT_SPRING]).unwrap();
+        underground.flow().unwrap();
+        assert_eq!(underground.scan_rows(), 1..=13);
+        assert_eq!(underground.water_counts(), WaterCounts { flowing: 28, settled: 29 });
+        // Just the lower reservoir
+        assert_eq!(underground.water_counts_in(10..=13), WaterCounts { flowing: 8, settled: 15 });
+    }
+
+    #[test]
+    fn multiple_springs_test() {
+        // A second copy of the example, 100 squares to the right, under its own spring
+        let mut veins = parse_veins(&example()).unwrap();
+        let shifted: Vec<ClayVein> = veins.iter().map(|vein| ClayVein {
+            xs: vein.xs.start() + 100..=vein.xs.end() + 100,
+            ys: vein.ys.clone()
+        }).collect();
+        veins.extend(shifted);
+
+        let mut underground = Underground::new(&veins, &[DEFAULT_SPRING, Coordinate { x: 600, y: 0 }]).unwrap();
+        underground.flow().unwrap();
+        assert_eq!(underground.water_counts(), WaterCounts { flowing: 56, settled: 58 });
+    }
+
+    #[test]
+    fn parse_vein_tests() {
+        assert_eq!("x=495, y=2..7".parse::<ClayVein>().unwrap(), ClayVein { xs: 495..=495, ys: 2..=7 });
+        assert_eq!("y=7, x=501..495".parse::<ClayVein>().unwrap(), ClayVein { xs: 495..=501, ys: 7..=7 });
+        assert_eq!("x=5, y=7".parse::<ClayVein>().unwrap(), ClayVein { xs: 5..=5, ys: 7..=7 });
+
+        let error = parse_veins(&["x=495, y=2..7".to_string(), "".to_string(), "x=495, x=2..7".to_string()]).unwrap_err();
+        assert_eq!(error.to_string(), "Line 3: x is given more than once");
+        assert!("x=495".parse::<ClayVein>().is_err());
+        assert!("x=495, y=2...7".parse::<ClayVein>().is_err());
+        assert!("z=1, y=2".parse::<ClayVein>().is_err());
+    }
+
+    #[test]
+    fn q2_test() {
+        assert_eq!(_q2(example()).unwrap(), 29);
+    }
+}
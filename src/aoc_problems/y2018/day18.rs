@@ -0,0 +1,308 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::prelude::*;
+use std::mem;
+use std::result;
+
+use aoc_problems::cycle;
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+enum Acre {
+    Open,
+    Trees,
+    Lumberyard,
+}
+
+impl Acre {
+    fn new(acre_symbol: char) -> Acre {
+        match acre_symbol {
+            '.' => Acre::Open,
+            '|' => Acre::Trees,
+            '#' => Acre::Lumberyard,
+            _ => panic!()
+        }
+    }
+}
+
+impl fmt::Display for Acre {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Acre::Open => write!(f, "."),
+            Acre::Trees => write!(f, "|"),
+            Acre::Lumberyard => write!(f, "#"),
+        }
+    }
+}
+
+// Buffers reused from one minute to the next, so that stepping doesn't allocate
+#[derive(Default)]
+struct Scratch {
+    trees: Vec<u8>,
+    lumberyards: Vec<u8>,
+    // Number of trees or lumberyards in each square and its left and right neighbours
+    tree_rows: Vec<u8>,
+    lumberyard_rows: Vec<u8>
+}
+
+// Trees and lumberyards are each held as a flat array of 0s and 1s, with a border of open acres
+// all the way round so that every acre in the grove has eight neighbours
+struct Grove {
+    width: usize,
+    height: usize,
+    trees: Vec<u8>,
+    lumberyards: Vec<u8>,
+    scratch: Scratch
+}
+
+impl Grove {
+    fn new(grove_rows: Vec<String>) -> Grove {
+        let width = grove_rows[0].len();
+        let height = grove_rows.len();
+        let stride = width + 2;
+
+        let mut trees = vec![0; stride * (height + 2)];
+        let mut lumberyards = vec![0; stride * (height + 2)];
+        for (y, grove_row) in grove_rows.iter().enumerate() {
+            for (x, acre_symbol) in grove_row.chars().enumerate() {
+                let idx = (y + 1) * stride + x + 1;
+                match Acre::new(acre_symbol) {
+                    Acre::Open => {},
+                    Acre::Trees => trees[idx] = 1,
+                    Acre::Lumberyard => lumberyards[idx] = 1,
+                }
+            }
+        }
+
+        Grove {
+            width,
+            height,
+            trees,
+            lumberyards,
+            scratch: Scratch::default()
+        }
+    }
+
+    fn stride(&self) -> usize {
+        self.width + 2
+    }
+
+    fn acre_at(&self, x: usize, y: usize) -> Acre {
+        let idx = (y + 1) * self.stride() + x + 1;
+        match (self.trees[idx], self.lumberyards[idx]) {
+            (1, _) => Acre::Trees,
+            (_, 1) => Acre::Lumberyard,
+            _ => Acre::Open,
+        }
+    }
+
+    fn increment_minute(&mut self) {
+        let stride = self.stride();
+        let len = self.trees.len();
+        let scratch = &mut self.scratch;
+        for buffer in &mut [&mut scratch.trees, &mut scratch.lumberyards, &mut scratch.tree_rows, &mut scratch.lumberyard_rows] {
+            buffer.resize(len, 0);
+        }
+
+        // Count along each row first, so that each acre then only needs to add up three of these
+        for idx in 1..len - 1 {
+            scratch.tree_rows[idx] = self.trees[idx - 1] + self.trees[idx] + self.trees[idx + 1];
+            scratch.lumberyard_rows[idx] = self.lumberyards[idx - 1] + self.lumberyards[idx] + self.lumberyards[idx + 1];
+        }
+
+        for y in 1..=self.height {
+            for idx in y * stride + 1..=y * stride + self.width {
+                let is_trees = self.trees[idx];
+                let is_lumberyard = self.lumberyards[idx];
+                let trees = scratch.tree_rows[idx - stride] + scratch.tree_rows[idx] + scratch.tree_rows[idx + stride] - is_trees;
+                let lumberyards = scratch.lumberyard_rows[idx - stride] + scratch.lumberyard_rows[idx] + scratch.lumberyard_rows[idx + stride] - is_lumberyard;
+
+                let (next_trees, next_lumberyard) = match (is_trees, is_lumberyard) {
+                    // Open ground fills with trees if at least three neighbours are trees
+                    (0, 0) => ((trees >= 3) as u8, 0),
+                    // Trees become a lumberyard if at least three neighbours are lumberyards
+                    (1, _) => ((lumberyards < 3) as u8, (lumberyards >= 3) as u8),
+                    // A lumberyard stays only if it's next to trees and another lumberyard
+                    _ => (0, (lumberyards >= 1 && trees >= 1) as u8),
+                };
+                scratch.trees[idx] = next_trees;
+                scratch.lumberyards[idx] = next_lumberyard;
+            }
+        }
+
+        mem::swap(&mut self.trees, &mut scratch.trees);
+        mem::swap(&mut self.lumberyards, &mut scratch.lumberyards);
+    }
+
+    fn after_minute(&self) -> Grove {
+        let mut next = self.clone();
+        next.increment_minute();
+        next
+    }
+
+    fn resource_value(&self) -> usize {
+        let trees: usize = self.trees.iter().map(|&acre| acre as usize).sum();
+        let lumberyards: usize = self.lumberyards.iter().map(|&acre| acre as usize).sum();
+        trees * lumberyards
+    }
+}
+
+// The scratch buffers aren't part of the grove's state, so are neither copied nor compared
+impl Clone for Grove {
+    fn clone(&self) -> Grove {
+        Grove {
+            width: self.width,
+            height: self.height,
+            trees: self.trees.clone(),
+            lumberyards: self.lumberyards.clone(),
+            scratch: Scratch::default()
+        }
+    }
+}
+
+impl PartialEq for Grove {
+    fn eq(&self, other: &Grove) -> bool {
+        self.width == other.width && self.trees == other.trees && self.lumberyards == other.lumberyards
+    }
+}
+
+impl Eq for Grove {}
+
+impl Hash for Grove {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.trees.hash(state);
+        self.lumberyards.hash(state);
+    }
+}
+
+impl fmt::Display for Grove {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", self.acre_at(x, y))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn q1(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    let grove_strings: Vec<String> = f_contents.lines().map(|x: &str| {
+        x.trim().to_string()
+    }).collect();
+
+    _q1(grove_strings).unwrap()
+}
+
+fn _q1(grove_strings: Vec<String>) -> Result<usize> {
+    let mut grove = Grove::new(grove_strings);
+
+    for _ in 0..10 {
+        grove.increment_minute();
+    }
+
+    Ok(grove.resource_value())
+}
+
+pub fn q2(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    let grove_strings: Vec<String> = f_contents.lines().map(|x: &str| {
+        x.trim().to_string()
+    }).collect();
+
+    _q2(grove_strings).unwrap()
+}
+
+fn _q2(grove_strings: Vec<String>) -> Result<usize> {
+    let grove = cycle::state_after(Grove::new(grove_strings), 1_000_000_000, Grove::after_minute);
+
+    Ok(grove.resource_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<String> {
+        vec![
+            ".#.#...|#.".to_string(),
+            ".....#|##|".to_string(),
+            ".|..|...#.".to_string(),
+            "..|#.....#".to_string(),
+            "#.#|||#|#|".to_string(),
+            "...#.||...".to_string(),
+            ".|....|...".to_string(),
+            "||...#|.#|".to_string(),
+            "|.||||..|.".to_string(),
+            "...#.|..|.".to_string(),
+        ]
+    }
+
+    #[test]
+    fn q1_test() {
+        assert_eq!(_q1(example()).unwrap(), 1147);
+    }
+
+    // One minute of the puzzle's rules, worked out acre by acre
+    fn naive_minute(rows: &[Vec<char>]) -> Vec<Vec<char>> {
+        let (width, height) = (rows[0].len() as i64, rows.len() as i64);
+        (0..height).map(|y| (0..width).map(|x| {
+            let neighbours: Vec<char> = (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+                .filter(|&(dx, dy)| (dx, dy) != (0, 0))
+                .map(|(dx, dy)| (x + dx, y + dy))
+                .filter(|&(nx, ny)| nx >= 0 && ny >= 0 && nx < width && ny < height)
+                .map(|(nx, ny)| rows[ny as usize][nx as usize])
+                .collect();
+            let count = |c: char| neighbours.iter().filter(|&&n| n == c).count();
+            match rows[y as usize][x as usize] {
+                '.' if count('|') >= 3 => '|',
+                '|' if count('#') >= 3 => '#',
+                '#' if count('#') == 0 || count('|') == 0 => '.',
+                acre => acre,
+            }
+        }).collect()).collect()
+    }
+
+    #[test]
+    fn matches_naive_rules_on_a_larger_grove() {
+        // Pseudo-random grove from a linear congruential generator
+        let mut seed: u64 = 7;
+        let mut rows: Vec<Vec<char>> = (0..40).map(|_| (0..70).map(|_| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ['.', '.', '|', '#'][(seed >> 60) as usize % 4]
+        }).collect()).collect();
+
+        let mut grove = Grove::new(rows.iter().map(|row| row.iter().collect()).collect());
+        for _ in 0..30 {
+            grove.increment_minute();
+            rows = naive_minute(&rows);
+            let expected: String = rows.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+            assert_eq!(grove.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn cycle_test() {
+        let history = cycle::simulate_until_repeat(Grove::new(example()), u64::MAX, Grove::after_minute);
+        let found = history.cycle.unwrap();
+        assert_eq!(history.states[found.start].to_string(), history.states[found.start + found.period - 1].after_minute().to_string());
+
+        let mut grove = Grove::new(example());
+        for _ in 0..100 {
+            grove.increment_minute();
+        }
+        assert_eq!(history.state_after(100).unwrap().to_string(), grove.to_string());
+    }
+}
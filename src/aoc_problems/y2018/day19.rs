@@ -0,0 +1,51 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+
+use super::elfcode::{Program, Registers};
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+pub fn q1(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    let input_list: Vec<String> = f_contents.lines().map(|x: &str| {
+        x.trim().to_string()
+    }).collect();
+
+    _q1(input_list).unwrap()
+}
+
+fn _q1(instruction_list: Vec<String>) -> Result<usize> {
+    let program = Program::new(&instruction_list)?;
+
+    let mut registers: Registers = [0; 6];
+    program.run(&mut registers);
+
+    Ok(registers[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn q1_test() {
+        let instruction_list: Vec<String> = "
+        #ip 0
+        seti 5 0 1
+        seti 6 0 2
+        addi 0 1 0
+        addr 1 2 3
+        setr 1 0 0
+        seti 8 0 4
+        seti 9 0 5".trim().lines().map(|s| s.trim().to_string()).collect();
+        assert_eq!(
+            _q1(instruction_list).unwrap(), 6
+        );
+    }
+
+}
@@ -0,0 +1,401 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+use std::usize;
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+struct Coordinate {
+    x: i32,
+    y: i32
+}
+
+impl Coordinate {
+    fn new(x: i32, y: i32) -> Coordinate {
+        Coordinate { x, y }
+    }
+
+    fn to_the(&self, direction: Direction) -> Coordinate {
+        match direction {
+            Direction::Up => Coordinate { x: self.x, y: self.y + 1 },
+            Direction::Down => Coordinate { x: self.x, y: self.y - 1 },
+            Direction::Left => Coordinate { x: self.x - 1, y: self.y },
+            Direction::Right => Coordinate { x: self.x + 1, y: self.y },
+        }
+    }
+}
+
+impl Ord for Coordinate {
+    fn cmp(&self, other: &Coordinate) -> cmp::Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+impl PartialOrd for Coordinate {
+    fn partial_cmp(&self, other: &Coordinate) -> Option<cmp::Ordering> {
+        Some((self.y, self.x).cmp(&(other.y, other.x)))
+    }
+}
+
+impl fmt::Debug for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// Doors run both ways, so every door appears in the links of both rooms it joins
+struct RoomPlan {
+    room_links: HashMap<Coordinate, BTreeSet<Coordinate>>
+}
+
+impl RoomPlan {
+    fn new() -> Result<RoomPlan> {
+        // (0, 0) is marked as a starting room
+        Ok(
+            RoomPlan {
+                room_links: HashMap::new()
+            }
+        )
+    }
+
+    fn from_regex(path_regex: &str) -> Result<RoomPlan> {
+        let path_regex = path_regex.trim();
+        if !(path_regex.starts_with('^') && path_regex.ends_with('$')) || path_regex.len() < 2 {
+            return err!("Input regex does not start with ^ and end with $");
+        }
+
+        let path_regex: Vec<char> = path_regex.chars().collect();
+        let mut room_plan = RoomPlan::new()?;
+        room_plan.parse_regex(&path_regex[1..path_regex.len()-1])?;
+
+        Ok(room_plan)
+    }
+
+    fn add_door_between_rooms(&mut self, from: Coordinate, to: Coordinate) {
+        self.room_links.entry(from).or_default().insert(to);
+        self.room_links.entry(to).or_default().insert(from);
+    }
+
+    // Walks every route at once. `current` holds every room the routes so far could have reached;
+    // each open group remembers the rooms it started from and the rooms its finished branches
+    // reached, which become the current rooms once the group closes.
+    fn parse_regex(&mut self, path_regex: &[char]) -> Result<()> {
+        let starting_coord = Coordinate::new(0, 0);
+        self.room_links.entry(starting_coord).or_default();
+
+        let mut current: BTreeSet<Coordinate> = BTreeSet::new();
+        current.insert(starting_coord);
+        let mut groups: Vec<(BTreeSet<Coordinate>, BTreeSet<Coordinate>)> = Vec::new();
+
+        for (idx, &c) in path_regex.iter().enumerate() {
+            match c {
+                'N' | 'E' | 'W' | 'S' => {
+                    let direction = match c {
+                        'N' => Direction::Up,
+                        'E' => Direction::Right,
+                        'W' => Direction::Left,
+                        _ => Direction::Down,
+                    };
+
+                    let mut next: BTreeSet<Coordinate> = BTreeSet::new();
+                    for coord in current {
+                        let next_coord = coord.to_the(direction);
+                        self.add_door_between_rooms(coord, next_coord);
+                        next.insert(next_coord);
+                    }
+                    current = next;
+                },
+                '(' => {
+                    groups.push((current.clone(), BTreeSet::new()));
+                },
+                '|' => {
+                    let (starts, ends) = match groups.last_mut() {
+                        Some(group) => group,
+                        None => return err!("'|' at position {} is outside any brackets", idx + 1),
+                    };
+                    ends.extend(current);
+                    current = starts.clone();
+                },
+                ')' => {
+                    let (_, mut ends) = match groups.pop() {
+                        Some(group) => group,
+                        None => return err!("')' at position {} has no matching '('", idx + 1),
+                    };
+                    ends.extend(current);
+                    current = ends;
+                },
+                x => {
+                    return err!("Cannot parse {:?} at position {}", x, idx + 1)
+                }
+            }
+        }
+
+        if !groups.is_empty() {
+            return err!("{} bracket(s) left open", groups.len());
+        }
+
+        Ok(())
+    }
+
+    fn distances(&self) -> DistanceMap {
+        self.distances_from(Coordinate::new(0, 0))
+    }
+
+    fn distances_from(&self, start: Coordinate) -> DistanceMap {
+        let mut distances: HashMap<Coordinate, usize> = HashMap::new();
+        let mut previous: HashMap<Coordinate, Coordinate> = HashMap::new();
+        distances.insert(start, 0);
+
+        let mut queue: VecDeque<Coordinate> = VecDeque::new();
+        queue.push_back(start);
+        while let Some(c) = queue.pop_front() {
+            let next_distance = distances[&c] + 1;
+            for &neighbour in self.room_links.get(&c).into_iter().flatten() {
+                if distances.contains_key(&neighbour) {
+                    continue;
+                }
+                distances.insert(neighbour, next_distance);
+                previous.insert(neighbour, c);
+                queue.push_back(neighbour);
+            }
+        }
+
+        DistanceMap { start, distances, previous }
+    }
+}
+
+// Drawn as in the puzzle, with north at the top and the starting room marked `X`
+impl fmt::Display for RoomPlan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let min_x = self.room_links.keys().map(|c| c.x).min().unwrap_or(0);
+        let max_x = self.room_links.keys().map(|c| c.x).max().unwrap_or(0);
+        let min_y = self.room_links.keys().map(|c| c.y).min().unwrap_or(0);
+        let max_y = self.room_links.keys().map(|c| c.y).max().unwrap_or(0);
+
+        let width = 2 * (max_x - min_x + 1) as usize + 1;
+        let height = 2 * (max_y - min_y + 1) as usize + 1;
+        let mut grid: Vec<Vec<char>> = vec![vec!['#'; width]; height];
+        for (&room, neighbours) in &self.room_links {
+            let col = 2 * (room.x - min_x) as usize + 1;
+            let row = 2 * (max_y - room.y) as usize + 1;
+            grid[row][col] = if room == Coordinate::new(0, 0) { 'X' } else { '.' };
+            if neighbours.contains(&room.to_the(Direction::Right)) {
+                grid[row][col + 1] = '|';
+            }
+            if neighbours.contains(&room.to_the(Direction::Up)) {
+                grid[row - 1][col] = '-';
+            }
+        }
+
+        for row in grid {
+            writeln!(f, "{}", row.into_iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+// The fewest doors needed to reach each room from `start`
+struct DistanceMap {
+    start: Coordinate,
+    distances: HashMap<Coordinate, usize>,
+    // The room each room is first reached from
+    previous: HashMap<Coordinate, Coordinate>
+}
+
+impl DistanceMap {
+    // Neither part asks about a particular room, but this and `path_to` can
+    #[allow(dead_code)]
+    fn distance_to(&self, room: Coordinate) -> Option<usize> {
+        self.distances.get(&room).cloned()
+    }
+
+    // Ties go to the southernmost, then westernmost, room
+    fn furthest_room(&self) -> Option<(Coordinate, usize)> {
+        self.distances.iter()
+            .max_by_key(|&(&room, &distance)| (distance, cmp::Reverse(room)))
+            .map(|(&room, &distance)| (room, distance))
+    }
+
+    fn rooms_at_least(&self, doors: usize) -> usize {
+        self.distances.values().filter(|&&distance| distance >= doors).count()
+    }
+
+    // Every room along a shortest route, from the start to `room` inclusive
+    #[allow(dead_code)]
+    fn path_to(&self, room: Coordinate) -> Option<Vec<Coordinate>> {
+        if !self.distances.contains_key(&room) {
+            return None;
+        }
+
+        let mut path = vec![room];
+        let mut current = room;
+        while current != self.start {
+            current = self.previous[&current];
+            path.push(current);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+pub fn q1(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents = f_contents.trim().to_string();
+
+    _q1(f_contents).unwrap()
+}
+
+fn _q1(path_regex: String) -> Result<usize> {
+    match RoomPlan::from_regex(&path_regex)?.distances().furthest_room() {
+        Some((_, distance)) => Ok(distance),
+        None => err!("No rooms in distance map"),
+    }
+}
+
+pub fn q2(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents = f_contents.trim().to_string();
+
+    _q2(f_contents).unwrap()
+}
+
+fn _q2(path_regex: String) -> Result<usize> {
+    Ok(RoomPlan::from_regex(&path_regex)?.distances().rooms_at_least(1000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day20_q1_test1() {
+        assert_eq!(
+            _q1("^WNE$".to_string()).unwrap(), 3
+        );
+    }
+
+    #[test]
+    fn day20_q1_test2() {
+        assert_eq!(
+            _q1("^ENWWW(NEEE|SSE(EE|N))$".to_string()).unwrap(), 10
+        );
+    }
+
+    #[test]
+    fn day20_q1_test3() {
+        assert_eq!(
+            _q1("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$".to_string()).unwrap(), 18
+        );
+    }
+
+    #[test]
+    fn day20_q1_test4() {
+        assert_eq!(
+            _q1(
+                "^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$"
+                .to_string()
+            ).unwrap(), 23
+        );
+    }
+
+    #[test]
+    fn day20_q1_test5() {
+        assert_eq!(
+            _q1(
+                "^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$"
+                .to_string()
+            ).unwrap(), 31
+        );
+    }
+
+    #[test]
+    fn branches_rejoin_after_a_group() {
+        // Both the northern and southern rooms have a door east
+        let room_plan = RoomPlan::from_regex("^(N|S)E$").unwrap();
+        assert_eq!(room_plan.room_links.len(), 5);
+        assert!(room_plan.room_links[&Coordinate::new(0, 1)].contains(&Coordinate::new(1, 1)));
+        assert!(room_plan.room_links[&Coordinate::new(0, -1)].contains(&Coordinate::new(1, -1)));
+
+        // An empty branch leaves the route where it was
+        let room_plan = RoomPlan::from_regex("^N(EW|)N$").unwrap();
+        assert!(room_plan.room_links[&Coordinate::new(0, 1)].contains(&Coordinate::new(0, 2)));
+        assert!(!room_plan.room_links.contains_key(&Coordinate::new(1, 2)));
+    }
+
+    #[test]
+    fn malformed_regexes_are_rejected() {
+        assert!(RoomPlan::from_regex("WNE$").is_err());
+        assert!(RoomPlan::from_regex("^N(E|W$").is_err());
+        assert!(RoomPlan::from_regex("^NE)$").is_err());
+        assert!(RoomPlan::from_regex("^N|E$").is_err());
+        assert!(RoomPlan::from_regex("^NXE$").is_err());
+    }
+
+    #[test]
+    fn render_test() {
+        let room_plan = RoomPlan::from_regex("^ENWWW(NEEE|SSE(EE|N))$").unwrap();
+        assert_eq!(
+            room_plan.to_string(),
+            "#########\n\
+             #.|.|.|.#\n\
+             #-#######\n\
+             #.|.|.|.#\n\
+             #-#####-#\n\
+             #.#.#X|.#\n\
+             #-#-#####\n\
+             #.|.|.|.#\n\
+             #########\n"
+        );
+    }
+
+    #[test]
+    fn distance_map_tests() {
+        let room_plan = RoomPlan::from_regex("^ENWWW(NEEE|SSE(EE|N))$").unwrap();
+        let distances = room_plan.distances();
+
+        assert_eq!(distances.furthest_room(), Some((Coordinate::new(1, -1), 10)));
+        assert_eq!(distances.rooms_at_least(9), 4);
+        assert_eq!(distances.distance_to(Coordinate::new(-2, -1)), Some(7));
+        assert_eq!(distances.distance_to(Coordinate::new(5, 5)), None);
+
+        let path = distances.path_to(Coordinate::new(1, -1)).unwrap();
+        assert_eq!(path.len(), 11);
+        assert_eq!(path[0], Coordinate::new(0, 0));
+        assert!(path.windows(2).all(|pair| room_plan.room_links[&pair[0]].contains(&pair[1])));
+
+        // Distances can be measured from any room
+        let from_corner = room_plan.distances_from(Coordinate::new(1, -1));
+        assert_eq!(from_corner.distance_to(Coordinate::new(0, 0)), Some(10));
+        assert_eq!(from_corner.path_to(Coordinate::new(1, -1)), Some(vec![Coordinate::new(1, -1)]));
+    }
+}
@@ -0,0 +1,189 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+
+use std::collections::HashSet;
+
+use super::elfcode::{Program, Registers};
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+// The program is expected to read `input_register` only to test it for equality against some
+// other value, and to stop when the test matches
+fn halting_values(program: &Program, input_register: usize) -> Result<HaltingValues<'_>> {
+    let readers: Vec<usize> = (0..program.instructions.len())
+        .filter(|&idx| program.instructions[idx].reads_register(input_register))
+        .collect();
+    let comparison = match readers.as_slice() {
+        [idx] => *idx,
+        [] => return err!("Register {} is never read", input_register),
+        _ => return err!("Register {} is read by instructions {:?}, not just one comparison", input_register, readers),
+    };
+
+    let instruction = &program.instructions[comparison];
+    if instruction.compared_with(input_register, &[0; 6]).is_none() {
+        return err!("Instruction {} ({}) is not an equality test", comparison, instruction.opcode.name);
+    }
+
+    Ok(HaltingValues {
+        program,
+        input_register,
+        comparison,
+        ip: 0,
+        registers: [0; 6],
+        seen_states: HashSet::new(),
+        seen_values: HashSet::new()
+    })
+}
+
+// Whether the program halts if the comparison matches `value`. As the comparison is the only
+// instruction that reads the input, the program either halts or comes back to the comparison,
+// which means this value didn't stop it; looping forever elsewhere would hang the main run too.
+fn halts_on(program: &Program, comparison: usize, input_register: usize, registers: &Registers, value: usize) -> bool {
+    let mut probe = *registers;
+    probe[input_register] = value;
+    program.run_until(comparison, &mut probe, comparison).is_none()
+}
+
+// Runs the program once with an input that never matches, yielding each value the input is tested
+// against that really would halt the program, in the order they come up, until the program's
+// state repeats
+struct HaltingValues<'a> {
+    program: &'a Program,
+    input_register: usize,
+    comparison: usize,
+    ip: usize,
+    registers: Registers,
+    seen_states: HashSet<Registers>,
+    seen_values: HashSet<usize>
+}
+
+impl<'a> Iterator for HaltingValues<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let instruction = &self.program.instructions[self.comparison];
+        loop {
+            if self.ip != self.comparison {
+                self.program.run_until(self.ip, &mut self.registers, self.comparison)?;
+            }
+
+            if !self.seen_states.insert(self.registers) {
+                return None;
+            }
+
+            let value = instruction.compared_with(self.input_register, &self.registers)?;
+            let halts = self.seen_values.insert(value)
+                && halts_on(self.program, self.comparison, self.input_register, &self.registers, value);
+
+            // Carry on as though the input had been anything else
+            self.registers[self.input_register] = value.wrapping_add(1);
+            self.ip = self.program.step(self.comparison, &mut self.registers);
+            if instruction.c != self.input_register {
+                self.registers[self.input_register] = 0;
+            }
+
+            if halts {
+                return Some(value);
+            }
+            if self.ip >= self.program.instructions.len() {
+                return None;
+            }
+        }
+    }
+}
+
+fn read_instructions(fname: String) -> Vec<String> {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents.lines().map(|x: &str| {
+        x.trim().to_string()
+    }).collect()
+}
+
+pub fn q1(fname: String) -> usize {
+    _q1(read_instructions(fname)).unwrap()
+}
+
+fn _q1(instruction_list: Vec<String>) -> Result<usize> {
+    match halting_values(&Program::new(&instruction_list)?, 0)?.next() {
+        Some(value) => Ok(value),
+        None => err!("No value of register 0 makes the program halt"),
+    }
+}
+
+pub fn q2(fname: String) -> usize {
+    _q2(read_instructions(fname)).unwrap()
+}
+
+fn _q2(instruction_list: Vec<String>) -> Result<usize> {
+    match halting_values(&Program::new(&instruction_list)?, 0)?.last() {
+        Some(value) => Ok(value),
+        None => err!("No value of register 0 makes the program halt"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Steps register 1 through 3, 6, 1, 4, 7, 2, 5, 0, ... and halts when it equals register 0
+    fn example() -> Vec<String> {
+        "
+        #ip 5
+        seti 0 0 1
+        addi 1 3 1
+        bani 1 7 1
+        eqrr 1 0 2
+        addr 2 5 5
+        seti 0 0 5".trim().lines().map(|s| s.trim().to_string()).collect()
+    }
+
+    #[test]
+    fn q1_test() {
+        assert_eq!(_q1(example()).unwrap(), 3);
+    }
+
+    #[test]
+    fn q2_test() {
+        assert_eq!(_q2(example()).unwrap(), 0);
+    }
+
+    #[test]
+    fn halting_values_test() {
+        let program = Program::new(&example()).unwrap();
+        assert_eq!(halting_values(&program, 0).unwrap().collect::<Vec<_>>(), vec![3, 6, 1, 4, 7, 2, 5, 0]);
+
+        // Register 2 is written before it is read, so is nothing like an input
+        assert!(halting_values(&program, 2).is_err());
+        assert!(halting_values(&program, 3).is_err());
+    }
+
+    #[test]
+    fn slow_halts_are_still_found() {
+        // Once register 1 matches, the program counts register 3 up to 5000 before halting, far
+        // longer than any fixed step budget for the probe would want to allow
+        let program = Program::new(&"
+            #ip 5
+            seti 0 0 1
+            addi 1 3 1
+            bani 1 7 1
+            eqrr 1 0 2
+            addr 2 5 5
+            seti 0 0 5
+            addi 3 1 3
+            gtri 3 4999 4
+            addr 4 5 5
+            seti 5 0 5".trim().lines().map(|s| s.trim().to_string()).collect::<Vec<_>>()).unwrap();
+
+        assert_eq!(halting_values(&program, 0).unwrap().collect::<Vec<_>>(), vec![3, 6, 1, 4, 7, 2, 5, 0]);
+    }
+
+}
@@ -0,0 +1,673 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+use std::usize;
+
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use regex::Regex;
+
+use aoc_problems::animation::{Animator, Colour, Frame, Glyph, Line, Target};
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+macro_rules! set {
+    ( $( $x:expr ),* ) => {  // Match zero or more comma delimited items
+        {
+            let mut temp_set = HashSet::new();  // Create a mutable HashSet
+            $(
+                temp_set.insert($x); // Insert each item matched into the HashSet
+            )*
+            temp_set // Return the populated HashSet
+        }
+    };
+}
+
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+struct Coordinate {
+    x: usize,
+    y: usize
+}
+
+impl Coordinate {
+    fn new(x: usize, y: usize) -> Coordinate {
+        Coordinate { x, y }
+    }
+}
+
+impl Ord for Coordinate {
+    fn cmp(&self, other: &Coordinate) -> cmp::Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+impl PartialOrd for Coordinate {
+    fn partial_cmp(&self, other: &Coordinate) -> Option<cmp::Ordering> {
+        Some((self.y, self.x).cmp(&(other.y, other.x)))
+    }
+}
+
+impl fmt::Debug for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+enum CaveType {
+    Rocky,
+    Narrow,
+    Wet
+}
+
+impl CaveType {
+    fn new(erosion_lvl: usize) -> CaveType {
+        match erosion_lvl % 3 {
+            0 => CaveType::Rocky,
+            1 => CaveType::Wet,
+            2 => CaveType::Narrow,
+            _ => panic!("Not possible!")
+        }
+    }
+
+    fn to_risk_lvl(&self) -> usize {
+        match &self {
+            CaveType::Rocky => 0,
+            CaveType::Wet => 1,
+            CaveType::Narrow => 2,
+        }
+    }
+}
+
+impl fmt::Display for CaveType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CaveType::Rocky => write!(f, "."),
+            CaveType::Narrow => write!(f, "|"),
+            CaveType::Wet => write!(f, "="),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
+enum ToolState {
+    ClimbingGear,
+    Torch,
+    Neither
+}
+
+impl ToolState {
+    fn other_allowed_tool(&self, cave_type: CaveType) -> ToolState {
+        match (cave_type, &self) {
+            (CaveType::Rocky, ToolState::Torch) => ToolState::ClimbingGear,
+            (CaveType::Rocky, ToolState::ClimbingGear) => ToolState::Torch,
+            (CaveType::Wet, ToolState::ClimbingGear) => ToolState::Neither,
+            (CaveType::Wet, ToolState::Neither) => ToolState::ClimbingGear,
+            (CaveType::Narrow, ToolState::Torch) => ToolState::Neither,
+            (CaveType::Narrow, ToolState::Neither) => ToolState::Torch,
+            _ => panic!("Disallowed combination!")
+        }
+    }
+
+    fn symbol(self) -> char {
+        match self {
+            ToolState::ClimbingGear => 'c',
+            ToolState::Torch => 't',
+            ToolState::Neither => 'n',
+        }
+    }
+}
+
+impl fmt::Display for ToolState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            ToolState::Torch => write!(f, "Torch"),
+            ToolState::ClimbingGear => write!(f, "Climbing Gear"),
+            ToolState::Neither => write!(f, "Neither"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+struct CaveState {
+    pos: Coordinate,
+    tool: ToolState
+}
+
+impl fmt::Display for CaveState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "At {} with {}", self.pos, self.tool)
+    }
+}
+
+impl CaveState {
+    fn new(pos: Coordinate, tool: ToolState) -> CaveState {
+        CaveState { pos, tool }
+    }
+}
+
+const EROSION_MODULO: usize = 20183;
+
+// Erosion levels of every region in the rectangle between the mouth and (`width`, `rows.len()`),
+// grown whenever a region outside it is asked for
+struct ErosionLevels {
+    depth: usize,
+    target: Coordinate,
+    width: usize,
+    rows: Vec<Vec<usize>>
+}
+
+impl ErosionLevels {
+    fn new(depth: usize, target: Coordinate) -> ErosionLevels {
+        ErosionLevels {
+            depth,
+            target,
+            width: 0,
+            rows: Vec::new()
+        }
+    }
+
+    // Needs the regions to the left and above to be known already. Everything is kept modulo
+    // 20183 before multiplying, so nothing can overflow however far out the region is.
+    fn erosion_level_from_neighbours(&self, x: usize, y: usize) -> usize {
+        let geologic_idx = match (x, y) {
+            (0, 0) => 0,
+            (x, y) if x == self.target.x && y == self.target.y => 0,
+            (x, 0) => (x % EROSION_MODULO) * 16807 % EROSION_MODULO,
+            (0, y) => (y % EROSION_MODULO) * 48271 % EROSION_MODULO,
+            (x, y) => self.rows[y][x - 1] * self.rows[y - 1][x] % EROSION_MODULO,
+        };
+
+        (geologic_idx + self.depth % EROSION_MODULO) % EROSION_MODULO
+    }
+
+    fn grow_to_include(&mut self, c: Coordinate) {
+        let height = self.rows.len();
+        if c.x < self.width && c.y < height {
+            return;
+        }
+
+        // Grow by at least half as much again, so that a search creeping outwards one region at
+        // a time doesn't extend every row on every step
+        let new_width = if c.x < self.width { self.width } else { cmp::max(c.x + 1, self.width * 3 / 2) };
+        let new_height = if c.y < height { height } else { cmp::max(c.y + 1, height * 3 / 2) };
+
+        for y in 0..height {
+            for x in self.width..new_width {
+                let erosion_lvl = self.erosion_level_from_neighbours(x, y);
+                self.rows[y].push(erosion_lvl);
+            }
+        }
+        self.width = new_width;
+
+        for y in height..new_height {
+            self.rows.push(Vec::with_capacity(new_width));
+            for x in 0..new_width {
+                let erosion_lvl = self.erosion_level_from_neighbours(x, y);
+                self.rows[y].push(erosion_lvl);
+            }
+        }
+    }
+
+    fn at(&mut self, c: Coordinate) -> usize {
+        self.grow_to_include(c);
+        self.rows[c.y][c.x]
+    }
+}
+
+struct Caves {
+    target: Coordinate,
+    erosion_levels: ErosionLevels,
+    allowed_tools: HashMap<CaveType, HashSet<ToolState>>
+}
+
+// `estimate` is the time taken so far plus a lower bound on the time still to go
+struct Visit {
+    point: CaveState,
+    estimate: usize,
+}
+
+impl Ord for Visit {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for Visit {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Visit {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate.eq(&other.estimate)
+    }
+}
+
+impl Eq for Visit {}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct RouteStep {
+    pos: Coordinate,
+    tool: ToolState,
+    // Whether this step was spent changing to `tool` rather than moving
+    switched_tool: bool,
+    time: usize
+}
+
+struct Route {
+    steps: Vec<RouteStep>
+}
+
+impl Route {
+    fn time(&self) -> usize {
+        self.steps.last().map_or(0, |step| step.time)
+    }
+
+    fn tool_switches(&self) -> Vec<RouteStep> {
+        self.steps.iter().filter(|step| step.switched_tool).cloned().collect()
+    }
+}
+
+impl Caves {
+    fn new(depth: usize, target: Coordinate) -> Caves {
+        use self::ToolState::*;
+
+        let mut allowed_tools: HashMap<CaveType, HashSet<ToolState>> = HashMap::new();
+        allowed_tools.insert(CaveType::Rocky, set![Torch, ClimbingGear]);
+        allowed_tools.insert(CaveType::Wet, set![ClimbingGear, Neither]);
+        allowed_tools.insert(CaveType::Narrow, set![Torch, Neither]);
+
+        Caves {
+            target,
+            erosion_levels: ErosionLevels::new(depth, target),
+            allowed_tools
+        }
+    }
+
+    fn surrounding_squares(&self, coord: Coordinate) -> Vec<Coordinate> {
+        let mut possible_squares = vec![
+            Coordinate { x: coord.x + 1, y: coord.y },
+            Coordinate { x: coord.x, y: coord.y + 1 },
+        ];
+
+        if coord.x > 0 {
+            possible_squares.push(Coordinate { x: coord.x - 1, y: coord.y });
+        }
+        if coord.y > 0 {
+            possible_squares.push(Coordinate { x: coord.x, y: coord.y - 1 });
+        }
+
+        possible_squares
+    }
+
+    fn cave_type(&mut self, c: Coordinate) -> CaveType {
+        CaveType::new(self.erosion_levels.at(c))
+    }
+
+    fn total_risk_level(&mut self) -> usize {
+        let mut total = 0;
+        for y in 0..=self.target.y {
+            for x in 0..=self.target.x {
+                total += self.cave_type(Coordinate::new(x, y)).to_risk_lvl();
+            }
+        }
+        total
+    }
+
+    fn is_allowed_tool(&mut self, cave_state: CaveState) -> bool {
+        let cave_type = self.cave_type(cave_state.pos);
+        self.allowed_tools[&cave_type].contains(&cave_state.tool)
+    }
+
+    fn free_squares_around(&mut self, state: CaveState) -> Vec<(CaveState, usize)> {
+        // 1 minute to move to adjacent squares
+        let mut possible_states: Vec<(CaveState, usize)> = Vec::new();
+        for c in self.surrounding_squares(state.pos) {
+            let cave_state = CaveState::new(c, state.tool);
+            if self.is_allowed_tool(cave_state) {
+                possible_states.push((cave_state, 1));
+            }
+        }
+
+        let current_cave_type = self.cave_type(state.pos);
+        let other_allowed_tool = state.tool.other_allowed_tool(current_cave_type);
+
+        // 7 minutes to change tools
+        possible_states.push((CaveState::new(state.pos, other_allowed_tool), 7));
+        possible_states
+    }
+
+    // Never more than the time still needed: every region takes a minute to cross, and unless the
+    // torch is already in hand, it must be switched to before reaching the target
+    fn lower_bound_from(&self, state: CaveState) -> usize {
+        let distance = cmp::max(state.pos.x, self.target.x) - cmp::min(state.pos.x, self.target.x)
+            + cmp::max(state.pos.y, self.target.y) - cmp::min(state.pos.y, self.target.y);
+        let switch = if state.tool == ToolState::Torch { 0 } else { 7 };
+        distance + switch
+    }
+
+    fn fastest_route(&mut self) -> Route {
+        let mut times: HashMap<CaveState, usize> = HashMap::new();
+        let mut previous: HashMap<CaveState, CaveState> = HashMap::new();
+        let start_state: CaveState = CaveState::new(Coordinate::new(0, 0), ToolState::Torch);
+        times.insert(start_state, 0);
+
+        let search_target = CaveState::new(self.target, ToolState::Torch);
+
+        let mut queue: BinaryHeap<Visit> = BinaryHeap::new();
+        queue.push(Visit { point: start_state, estimate: self.lower_bound_from(start_state) });
+
+        let mut visited: HashSet<CaveState> = HashSet::new();
+
+        while let Some(state) = queue.pop() {
+            if !visited.insert(state.point) {
+                continue;
+            }
+
+            if state.point == search_target {
+                break;
+            }
+
+            let time = times[&state.point];
+            for (neighbour, time_diff) in self.free_squares_around(state.point) {
+                let new_time = time + time_diff;
+                if times.get(&neighbour).is_none_or(|&t| new_time < t) {
+                    times.insert(neighbour, new_time);
+                    previous.insert(neighbour, state.point);
+                    queue.push(Visit {
+                        point: neighbour,
+                        estimate: new_time + self.lower_bound_from(neighbour),
+                    });
+                }
+            }
+        }
+
+        let mut steps: Vec<RouteStep> = Vec::new();
+        let mut current = search_target;
+        loop {
+            let before = previous.get(&current).cloned();
+            steps.push(RouteStep {
+                pos: current.pos,
+                tool: current.tool,
+                switched_tool: before.is_some_and(|b| b.tool != current.tool),
+                time: times[&current]
+            });
+            match before {
+                Some(b) => current = b,
+                None => break,
+            }
+        }
+        steps.reverse();
+
+        Route { steps }
+    }
+
+    fn fastest_path_to_target(&mut self) -> usize {
+        self.fastest_route().time()
+    }
+
+    fn map_rows(&self) -> Vec<Vec<char>> {
+        self.erosion_levels.rows.iter().enumerate().map(|(y, row)| {
+            row.iter().enumerate().map(|(x, &erosion_lvl)| {
+                if Coordinate::new(x, y) == self.target {
+                    'T'
+                } else {
+                    CaveType::new(erosion_lvl).to_string().chars().next().unwrap()
+                }
+            }).collect()
+        }).collect()
+    }
+
+    fn route_map<'a>(&'a self, route: &'a Route) -> RouteMap<'a> {
+        RouteMap { caves: self, steps: &route.steps }
+    }
+}
+
+// Shows as much of the cave as has been explored so far
+impl fmt::Display for Caves {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.map_rows() {
+            writeln!(f, "{}", row.into_iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+// The cave map with a route, or the start of one, drawn over it. Each region the route passes
+// through shows the tool in hand, `c`, `t` or `n`, or `*` where the tool was switched there. The
+// mouth is `M` and the target stays `T`.
+struct RouteMap<'a> {
+    caves: &'a Caves,
+    steps: &'a [RouteStep]
+}
+
+impl<'a> RouteMap<'a> {
+    fn rows(&self) -> Vec<Vec<char>> {
+        let mut rows = self.caves.map_rows();
+        for step in self.steps {
+            let symbol = if step.switched_tool { '*' } else { step.tool.symbol() };
+            rows[step.pos.y][step.pos.x] = symbol;
+        }
+        rows[0][0] = 'M';
+        rows[self.caves.target.y][self.caves.target.x] = 'T';
+        rows
+    }
+
+    // The route in yellow, with its latest step highlighted
+    fn frame(&self, caption: String) -> Frame {
+        let on_route: HashSet<Coordinate> = self.steps.iter().map(|step| step.pos).collect();
+        let latest = self.steps.last().map(|step| step.pos);
+        let lines = self.rows().into_iter().enumerate().map(|(y, row)| {
+            let glyphs = row.into_iter().enumerate().map(|(x, symbol)| {
+                let pos = Coordinate::new(x, y);
+                let colour = if on_route.contains(&pos) { Some(Colour::Yellow) } else { Some(Colour::Grey) };
+                Glyph { active: Some(pos) == latest, ..Glyph::new(symbol, colour) }
+            }).collect();
+            Line { glyphs, notes: Vec::new() }
+        }).collect();
+
+        Frame { caption, lines }
+    }
+}
+
+impl<'a> fmt::Display for RouteMap<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.into_iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+// The cave system's depth and target, from a scan like `depth: 510` then `target: 10,10`
+fn parse_scan(s: &str) -> Result<(usize, Coordinate)> {
+    lazy_static! {
+        static ref SCAN_RE: Regex = Regex::new(r"^depth: ([0-9]+)\s+target: ([0-9]+),([0-9]+)$").unwrap();
+    }
+
+    match SCAN_RE.captures(s.trim()) {
+        Some(caps) => Ok((caps[1].parse()?, Coordinate::new(caps[2].parse()?, caps[3].parse()?))),
+        None => err!("Cannot parse cave scan: {:?}", s.trim()),
+    }
+}
+
+fn read_scan(fname: String) -> String {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents
+}
+
+pub fn q1(fname: String) -> usize {
+    _q1(&read_scan(fname)).unwrap()
+}
+
+fn _q1(scan: &str) -> Result<usize> {
+    let (depth, target) = parse_scan(scan)?;
+    let mut caves = Caves::new(depth, target);
+
+    Ok(caves.total_risk_level())
+}
+
+pub fn q2(fname: String) -> usize {
+    _q2(&read_scan(fname)).unwrap()
+}
+
+fn _q2(scan: &str) -> Result<usize> {
+    let (depth, target) = parse_scan(scan)?;
+    let mut caves = Caves::new(depth, target);
+
+    Ok(caves.fastest_path_to_target())
+}
+
+// Walks the fastest route to the target a step at a time, and returns how long it takes
+pub fn animate(fname: String, target: Target) -> usize {
+    _animate(&read_scan(fname), target).unwrap()
+}
+
+fn _animate(scan: &str, target: Target) -> Result<usize> {
+    let (depth, target_pos) = parse_scan(scan)?;
+    let mut caves = Caves::new(depth, target_pos);
+    let route = caves.fastest_route();
+
+    let mut animator = Animator::new(target)?;
+    for (idx, step) in route.steps.iter().enumerate() {
+        let action = if step.switched_tool { "switched to" } else { "carrying" };
+        let caption = format!("Minute {}: at {} {} {}", step.time, step.pos, action, step.tool);
+        animator.show(RouteMap { caves: &caves, steps: &route.steps[..=idx] }.frame(caption))?;
+    }
+    let caption = format!("Reached the target in {} minutes, switching tools {} times", route.time(), route.tool_switches().len());
+    animator.show(caves.route_map(&route).frame(caption))?;
+
+    Ok(route.time())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day22_q1_test() {
+        assert_eq!(
+            _q1("depth: 510\ntarget: 10,10\n").unwrap(),
+            114
+        );
+        assert!(_q1("depth: 510").is_err());
+    }
+
+    #[test]
+    fn day22_q2_test() {
+        assert_eq!(
+            _q2("depth: 510\ntarget: 10,10").unwrap(),
+            45
+        );
+    }
+
+    #[test]
+    fn erosion_level_tests() {
+        let mut erosion_levels = ErosionLevels::new(510, Coordinate::new(10, 10));
+        assert_eq!(erosion_levels.at(Coordinate::new(0, 0)), 510);
+        assert_eq!(erosion_levels.at(Coordinate::new(1, 0)), 17317);
+        assert_eq!(erosion_levels.at(Coordinate::new(0, 1)), 8415);
+        assert_eq!(erosion_levels.at(Coordinate::new(1, 1)), 1805);
+        assert_eq!(erosion_levels.at(Coordinate::new(10, 10)), 510);
+
+        // Growing in a different order gives the same levels
+        let mut grown_sideways = ErosionLevels::new(510, Coordinate::new(10, 10));
+        grown_sideways.at(Coordinate::new(40, 3));
+        grown_sideways.at(Coordinate::new(2, 40));
+        for y in 0..40 {
+            for x in 0..40 {
+                assert_eq!(grown_sideways.at(Coordinate::new(x, y)), erosion_levels.at(Coordinate::new(x, y)));
+            }
+        }
+
+        // Far-off regions neither overflow nor recurse deeply
+        assert!(erosion_levels.at(Coordinate::new(3, 20_000)) < EROSION_MODULO);
+    }
+
+    #[test]
+    fn route_test() {
+        let mut caves = Caves::new(510, Coordinate::new(10, 10));
+        let route = caves.fastest_route();
+        assert_eq!(route.time(), 45);
+
+        let first = route.steps[0];
+        let last = route.steps[route.steps.len() - 1];
+        assert_eq!((first.pos, first.tool, first.time), (Coordinate::new(0, 0), ToolState::Torch, 0));
+        assert_eq!((last.pos, last.tool), (Coordinate::new(10, 10), ToolState::Torch));
+
+        for pair in route.steps.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            assert!(caves.is_allowed_tool(CaveState::new(to.pos, to.tool)));
+            if to.switched_tool {
+                assert_eq!((to.pos, to.time - from.time), (from.pos, 7));
+            } else {
+                assert_eq!((to.tool, to.time - from.time), (from.tool, 1));
+                assert!(caves.surrounding_squares(from.pos).contains(&to.pos));
+            }
+        }
+        let switches = route.tool_switches().len();
+        assert_eq!(route.steps.len() - 1 - switches + 7 * switches, 45);
+
+        let map = [
+            "M=.|=.|.|=.|=|=.|=.",
+            "t*nn*|||..|.=....==",
+            ".==|c...||=..|==.||",
+            "=.|.c..|.==.|==..=|",
+            "=|..c=...=.|==..==|",
+            "=||.c.=||=|=..|=|=|",
+            "|.=.c==|||..=..||||",
+            "|..=c||=.|==|====|.",
+            ".=..cc=..=|.|||.|=.",
+            ".====c=|||=|=.|=||=",
+            ".===|c|===T===||=.=",
+            "=|||.cc|==c.|=.|.=.",
+            "=.=|=.ccccc||==||||",
+            "||=|=...|==.=|====|",
+            "|=.=||===.|||===.=.",
+            "||.|==.|.|.||=||=.|",
+            ".|.|..|||||=.=..=|=",
+            ".=||.|=|..|=|===.|=",
+            "=...||.=|.....=.|..",
+        ];
+        assert_eq!(caves.route_map(&route).to_string(), map.iter().map(|row| format!("{}\n", row)).collect::<String>());
+    }
+
+    #[test]
+    fn animation_test() {
+        let dir = ::std::env::temp_dir().join(format!("aoc_day22_animation_{}", ::std::process::id()));
+        assert_eq!(_animate("depth: 510\ntarget: 10,10", Target::Directory(dir.clone())).unwrap(), 45);
+        let mut caves = Caves::new(510, Coordinate::new(10, 10));
+        let route = caves.fastest_route();
+
+        let mut frames: Vec<_> = ::std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+        frames.sort();
+        let read_frame = |idx: usize| ::std::fs::read_to_string(&frames[idx]).unwrap();
+        // A frame for each step of the route, then one for the whole route
+        assert_eq!(frames.len(), 29);
+        assert!(read_frame(0).starts_with("Minute 0: at (0, 0) carrying Torch\nM=.|=.|.|=.|=|=.|=.\n.|=|=|||..|.=....==\n"));
+        assert!(read_frame(3).starts_with("Minute 9: at (1, 1) switched to Neither\nM=.|=.|.|=.|=|=.|=.\nt*=|=|||..|.=....==\n"));
+        assert!(read_frame(27).starts_with("Minute 45: at (10, 10) switched to Torch\n"));
+        assert_eq!(read_frame(28), format!(
+            "Reached the target in 45 minutes, switching tools 3 times\n{}",
+            caves.route_map(&route)
+        ));
+        ::std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,622 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+use std::ops::{Add, Sub, AddAssign};
+use std::str::FromStr;
+
+use std::collections::BinaryHeap;
+
+use regex::Regex;
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+struct Coordinate {
+    x: i32,
+    y: i32,
+    z: i32
+}
+
+impl Coordinate {
+    fn new(x: i32, y: i32, z: i32) -> Coordinate {
+        Coordinate { x, y, z }
+    }
+
+    fn distance_from(&self, other: Self) -> usize {
+        (
+            (self.x - other.x).abs()
+            + (self.y - other.y).abs()
+            + (self.z - other.z).abs()
+        ) as usize
+    }
+}
+
+impl Ord for Coordinate {
+    fn cmp(&self, other: &Coordinate) -> cmp::Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+impl PartialOrd for Coordinate {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some((self.z, self.y, self.x).cmp(&(other.z, other.y, other.x)))
+    }
+}
+
+impl Add for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, other: Coordinate) -> Coordinate {
+        Coordinate {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub for Coordinate {
+    type Output = Coordinate;
+
+    fn sub(self, other: Coordinate) -> Coordinate {
+        Coordinate {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl AddAssign for Coordinate {
+    fn add_assign(&mut self, other: Self) {
+        *self = Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl fmt::Debug for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+struct Nanobot {
+    pos: Coordinate,
+    radius: usize
+}
+
+impl Nanobot {
+    fn new(pos: Coordinate, radius: usize) -> Nanobot {
+        Nanobot { pos, radius }
+    }
+
+    fn distance_from(&self, other: &Self) -> usize {
+        self.pos.distance_from(other.pos)
+    }
+
+    fn is_in_range_of(&self, pos: Coordinate) -> bool {
+        self.pos.distance_from(pos) <= self.radius
+    }
+}
+
+impl FromStr for Nanobot {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self> {
+        lazy_static! {
+            static ref NANOBOT_RE: Regex = Regex::new(
+                r"pos=<(?P<x>-?[0-9]+),(?P<y>-?[0-9]+),(?P<z>-?[0-9]+)>, r=(?P<r>[0-9]+)"
+            ).unwrap();
+        }
+
+        if !s.is_ascii() {
+            return err!("area must be in ASCII");
+        }
+
+        if s.lines().count() != 1 {
+            return err!("Only accepts 1 line");
+        }
+
+        if let Some(caps) = NANOBOT_RE.captures(s) {
+            return Ok(Nanobot::new(
+                Coordinate::new(
+                    caps["x"].parse()?,
+                    caps["y"].parse()?,
+                    caps["z"].parse()?
+                ),
+                caps["r"].parse()?
+            ));
+        }
+
+        err!("Cannot parse nanobot line: {}", s)
+    }
+}
+
+impl fmt::Display for Nanobot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Nanobot at {} with radius {}", self.pos, self.radius)
+    }
+}
+
+// Manhattan distance between the nearest points of two boxes, each given by its lowest and highest
+// corners, inclusive
+fn gap_between_boxes(a: (Coordinate, Coordinate), b: (Coordinate, Coordinate)) -> usize {
+    let axis_gap = |a_low: i32, a_high: i32, b_low: i32, b_high: i32| {
+        cmp::max(0, cmp::max(i64::from(a_low) - i64::from(b_high), i64::from(b_low) - i64::from(a_high)))
+    };
+    (axis_gap(a.0.x, a.1.x, b.0.x, b.1.x) + axis_gap(a.0.y, a.1.y, b.0.y, b.1.y) + axis_gap(a.0.z, a.1.z, b.0.z, b.1.z)) as usize
+}
+
+// The cube of side `size` whose lowest corner is `min`, including only the integer points
+// from `min` to `min + size - 1` along each axis
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct SearchBox {
+    min: Coordinate,
+    size: i32
+}
+
+impl SearchBox {
+    fn covering(nanobots: &[Nanobot]) -> SearchBox {
+        let reach = |nanobot: &Nanobot, sign: i64, axis: fn(&Coordinate) -> i32| i64::from(axis(&nanobot.pos)) + sign * nanobot.radius as i64;
+        let axes: [fn(&Coordinate) -> i32; 3] = [|c| c.x, |c| c.y, |c| c.z];
+
+        // Include the origin, so the search can never be pushed away from it
+        let lowest = axes.iter().map(|&axis| nanobots.iter().map(|n| reach(n, -1, axis)).min().unwrap_or(0).min(0)).min().unwrap();
+        let highest = axes.iter().map(|&axis| nanobots.iter().map(|n| reach(n, 1, axis)).max().unwrap_or(0).max(0)).max().unwrap();
+
+        let mut size: i64 = 1;
+        while lowest + size <= highest {
+            size *= 2;
+        }
+
+        SearchBox { min: Coordinate::new(lowest as i32, lowest as i32, lowest as i32), size: size as i32 }
+    }
+
+    fn max(&self) -> Coordinate {
+        self.min + Coordinate::new(self.size - 1, self.size - 1, self.size - 1)
+    }
+
+    // Manhattan distance from `c` to the nearest point in the box
+    fn distance_to(&self, c: Coordinate) -> usize {
+        gap_between_boxes((self.min, self.max()), (c, c))
+    }
+
+    fn children(&self) -> Vec<SearchBox> {
+        let half = self.size / 2;
+        let mut children = Vec::with_capacity(8);
+        for &dx in &[0, half] {
+            for &dy in &[0, half] {
+                for &dz in &[0, half] {
+                    children.push(SearchBox { min: self.min + Coordinate::new(dx, dy, dz), size: half });
+                }
+            }
+        }
+        children
+    }
+}
+
+const INDEX_LEAF_SIZE: usize = 8;
+
+struct IndexNode {
+    // Box around the centres of the bots under this node
+    min: Coordinate,
+    max: Coordinate,
+    max_radius: usize,
+    // Either a leaf, holding `order[first..last]`, or a branch with two children
+    contents: IndexContents
+}
+
+enum IndexContents {
+    Leaf(usize, usize),
+    Branch(usize, usize)
+}
+
+// A bounding volume hierarchy over the bots. Each node knows the box around its bots' centres and
+// their largest radius, so any branch too far away for even its largest bot to reach is skipped.
+// Queries give indices into `nanobots`.
+struct NanobotIndex {
+    nanobots: Vec<Nanobot>,
+    order: Vec<usize>,
+    nodes: Vec<IndexNode>
+}
+
+impl NanobotIndex {
+    fn new(nanobots: Vec<Nanobot>) -> NanobotIndex {
+        let mut index = NanobotIndex {
+            order: (0..nanobots.len()).collect(),
+            nanobots,
+            nodes: Vec::new()
+        };
+        if !index.nanobots.is_empty() {
+            let len = index.order.len();
+            index.build(0, len);
+        }
+        index
+    }
+
+    // Adds the node covering `order[first..last]` and returns its position in `nodes`
+    fn build(&mut self, first: usize, last: usize) -> usize {
+        let bots: Vec<Nanobot> = self.order[first..last].iter().map(|&idx| self.nanobots[idx]).collect();
+        let min = Coordinate::new(
+            bots.iter().map(|n| n.pos.x).min().unwrap(),
+            bots.iter().map(|n| n.pos.y).min().unwrap(),
+            bots.iter().map(|n| n.pos.z).min().unwrap()
+        );
+        let max = Coordinate::new(
+            bots.iter().map(|n| n.pos.x).max().unwrap(),
+            bots.iter().map(|n| n.pos.y).max().unwrap(),
+            bots.iter().map(|n| n.pos.z).max().unwrap()
+        );
+        let max_radius = bots.iter().map(|n| n.radius).max().unwrap();
+
+        let node_idx = self.nodes.len();
+        self.nodes.push(IndexNode { min, max, max_radius, contents: IndexContents::Leaf(first, last) });
+        if last - first <= INDEX_LEAF_SIZE {
+            return node_idx;
+        }
+
+        // Split at the median along the widest axis
+        let extent = max - min;
+        let axis: fn(&Coordinate) -> i32 = if extent.x >= extent.y && extent.x >= extent.z {
+            |c| c.x
+        } else if extent.y >= extent.z {
+            |c| c.y
+        } else {
+            |c| c.z
+        };
+        let nanobots = &self.nanobots;
+        self.order[first..last].sort_by_key(|&idx| axis(&nanobots[idx].pos));
+
+        let middle = first + (last - first) / 2;
+        let left = self.build(first, middle);
+        let right = self.build(middle, last);
+        self.nodes[node_idx].contents = IndexContents::Branch(left, right);
+        node_idx
+    }
+
+    // Every bot for which `matches` holds, looking only under nodes for which `may_match` holds
+    fn query<F, G>(&self, may_match: F, matches: G) -> Vec<usize>
+        where F: Fn(&IndexNode) -> bool,
+              G: Fn(&Nanobot) -> bool
+    {
+        let mut found: Vec<usize> = Vec::new();
+        let mut stack: Vec<usize> = if self.nodes.is_empty() { Vec::new() } else { vec![0] };
+        while let Some(node_idx) = stack.pop() {
+            let node = &self.nodes[node_idx];
+            if !may_match(node) {
+                continue;
+            }
+            match node.contents {
+                IndexContents::Leaf(first, last) => {
+                    found.extend(self.order[first..last].iter().cloned().filter(|&idx| matches(&self.nanobots[idx])));
+                },
+                IndexContents::Branch(left, right) => {
+                    stack.push(left);
+                    stack.push(right);
+                },
+            }
+        }
+        found.sort();
+        found
+    }
+
+    // Bots whose centres are within `distance` of `c`
+    fn centred_within(&self, c: Coordinate, distance: usize) -> Vec<usize> {
+        self.query(
+            |node| gap_between_boxes((node.min, node.max), (c, c)) <= distance,
+            |nanobot| nanobot.pos.distance_from(c) <= distance
+        )
+    }
+
+    // Bots with `c` in range
+    fn covering(&self, c: Coordinate) -> Vec<usize> {
+        self.query(
+            |node| gap_between_boxes((node.min, node.max), (c, c)) <= node.max_radius,
+            |nanobot| nanobot.is_in_range_of(c)
+        )
+    }
+
+    // Bots whose ranges share at least one point with the range of `nanobot`, including itself
+    fn overlapping(&self, nanobot: &Nanobot) -> Vec<usize> {
+        self.query(
+            |node| gap_between_boxes((node.min, node.max), (nanobot.pos, nanobot.pos)) <= node.max_radius + nanobot.radius,
+            |other| other.distance_from(nanobot) <= other.radius + nanobot.radius
+        )
+    }
+
+    // Bots whose range reaches at least one point of the box. This can only fall as boxes are
+    // split, and is exact once the box is a single point.
+    fn count_reaching(&self, search_box: &SearchBox) -> usize {
+        let bounds = (search_box.min, search_box.max());
+        self.query(
+            |node| gap_between_boxes((node.min, node.max), bounds) <= node.max_radius,
+            |nanobot| search_box.distance_to(nanobot.pos) <= nanobot.radius
+        ).len()
+    }
+
+    // The largest set of bots whose ranges all overlap one another. Every bot in range of a single
+    // point is in such a set, so its size bounds how many bots any point can be in range of.
+    fn max_clique(&self) -> Vec<usize> {
+        let words = self.nanobots.len().div_ceil(64);
+        let adjacency: Vec<Vec<u64>> = self.nanobots.iter().enumerate().map(|(idx, nanobot)| {
+            let mut neighbours = vec![0; words];
+            for other in self.overlapping(nanobot) {
+                if other != idx {
+                    neighbours[other / 64] |= 1 << (other % 64);
+                }
+            }
+            neighbours
+        }).collect();
+
+        let mut candidates = vec![0; words];
+        for idx in 0..self.nanobots.len() {
+            candidates[idx / 64] |= 1 << (idx % 64);
+        }
+
+        let mut best: Vec<usize> = Vec::new();
+        extend_clique(&adjacency, &mut Vec::new(), candidates, &mut best);
+        best.sort();
+        best
+    }
+}
+
+fn bits_set(bits: &[u64]) -> Vec<usize> {
+    let mut set = Vec::new();
+    for (word_idx, &word) in bits.iter().enumerate() {
+        let mut word = word;
+        while word != 0 {
+            set.push(word_idx * 64 + word.trailing_zeros() as usize);
+            word &= word - 1;
+        }
+    }
+    set
+}
+
+fn count_common(a: &[u64], b: &[u64]) -> usize {
+    a.iter().zip(b).map(|(x, y)| (x & y).count_ones() as usize).sum()
+}
+
+// Bron-Kerbosch with pivoting, abandoning any branch that can't beat the best clique so far
+fn extend_clique(adjacency: &[Vec<u64>], clique: &mut Vec<usize>, mut candidates: Vec<u64>, best: &mut Vec<usize>) {
+    let candidate_list = bits_set(&candidates);
+    if candidate_list.is_empty() {
+        if clique.len() > best.len() {
+            *best = clique.clone();
+        }
+        return;
+    }
+    if clique.len() + candidate_list.len() <= best.len() {
+        return;
+    }
+
+    // Any clique can be extended by the pivot or by something not next to it
+    let pivot = *candidate_list.iter().max_by_key(|&&idx| count_common(&candidates, &adjacency[idx])).unwrap();
+    for idx in candidate_list {
+        if idx != pivot && adjacency[pivot][idx / 64] & (1 << (idx % 64)) != 0 {
+            continue;
+        }
+
+        let next_candidates: Vec<u64> = candidates.iter().zip(&adjacency[idx]).map(|(c, n)| c & n).collect();
+        clique.push(idx);
+        extend_clique(adjacency, clique, next_candidates, best);
+        clique.pop();
+
+        candidates[idx / 64] &= !(1 << (idx % 64));
+        if clique.len() + count_common(&candidates, &candidates) <= best.len() {
+            return;
+        }
+    }
+}
+
+// The point in range of the most nanobots, nearest the origin if there are several, along with how
+// many bots are in range of it. Boxes are split best-first: the most bots possibly in range, then
+// the nearest to the origin, so the first single point taken from the queue is the answer.
+fn best_position(index: &NanobotIndex) -> (Coordinate, usize) {
+    let origin = Coordinate::new(0, 0, 0);
+    let start = SearchBox::covering(&index.nanobots);
+
+    let mut queue: BinaryHeap<(usize, cmp::Reverse<usize>, cmp::Reverse<i32>, SearchBox)> = BinaryHeap::new();
+    queue.push((index.count_reaching(&start), cmp::Reverse(start.distance_to(origin)), cmp::Reverse(start.size), start));
+
+    while let Some((count, _, _, search_box)) = queue.pop() {
+        if search_box.size == 1 {
+            return (search_box.min, count);
+        }
+        for child in search_box.children() {
+            queue.push((index.count_reaching(&child), cmp::Reverse(child.distance_to(origin)), cmp::Reverse(child.size), child));
+        }
+    }
+
+    unreachable!("The search space always contains a point")
+}
+
+pub fn q1(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents = f_contents.trim().to_string();
+
+    _q1(f_contents).unwrap()
+}
+
+fn _q1(nanobot_list: String) -> Result<usize> {
+    let nanobots: Result<Vec<Nanobot>> = nanobot_list
+        .trim()
+        .lines()
+        .map(|l| l.trim().parse())
+        .collect();
+
+    let nanobots = nanobots?;
+
+    let best_nanobot = *nanobots.iter().max_by_key(|nanobot| nanobot.radius).ok_or("No nanobots!")?;
+
+    let index = NanobotIndex::new(nanobots);
+    Ok(index.centred_within(best_nanobot.pos, best_nanobot.radius).len())
+}
+
+pub fn q2(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents = f_contents.trim().to_string();
+
+    _q2(f_contents).unwrap()
+}
+
+fn _q2(nanobot_list: String) -> Result<usize> {
+    let nanobots: Result<Vec<Nanobot>> = nanobot_list
+        .trim()
+        .lines()
+        .map(|l| l.trim().parse())
+        .collect();
+
+    let nanobots = nanobots?;
+
+    if nanobots.is_empty() {
+        return err!("No nanobots!");
+    }
+
+    let index = NanobotIndex::new(nanobots);
+    let (position, count) = best_position(&index);
+
+    if index.covering(position).len() != count {
+        return err!("{} was counted as in range of {} nanobots, but is in range of {}", position, count, index.covering(position).len());
+    }
+    let clique = index.max_clique();
+    if count > clique.len() {
+        return err!("{} is in range of {} nanobots, but at most {} overlap one another", position, count, clique.len());
+    }
+
+    Ok(position.distance_from(Coordinate::new(0, 0, 0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day23_q1() {
+        assert_eq!(
+            _q1("
+                pos=<0,0,0>, r=4
+                pos=<1,0,0>, r=1
+                pos=<4,0,0>, r=3
+                pos=<0,2,0>, r=1
+                pos=<0,5,0>, r=3
+                pos=<0,0,3>, r=1
+                pos=<1,1,1>, r=1
+                pos=<1,1,2>, r=1
+                pos=<1,3,1>, r=1
+            ".to_string()).unwrap(), 7
+        );
+    }
+
+    #[test]
+    fn day23_q2() {
+        assert_eq!(
+            _q2("
+                pos=<10,12,12>, r=2
+                pos=<12,14,12>, r=2
+                pos=<16,12,12>, r=4
+                pos=<14,14,14>, r=6
+                pos=<50,50,50>, r=200
+                pos=<10,10,10>, r=5
+            ".to_string()).unwrap(), 36
+        );
+    }
+
+    // Pseudo-random bots from a linear congruential generator, in a space small enough to check
+    // every point
+    fn random_nanobots(seed: u64, count: usize) -> Vec<Nanobot> {
+        let mut seed = seed;
+        let mut next = |modulus: u64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((seed >> 33) % modulus) as i32
+        };
+        (0..count)
+            .map(|_| Nanobot::new(Coordinate::new(next(30) - 5, next(30) - 5, next(30) - 5), next(12) as usize))
+            .collect()
+    }
+
+    #[test]
+    fn best_position_is_exhaustive_best() {
+        let nanobots = random_nanobots(23, 30);
+
+        let origin = Coordinate::new(0, 0, 0);
+        let mut best: Option<(usize, usize)> = None;
+        for x in -20..40 {
+            for y in -20..40 {
+                for z in -20..40 {
+                    let c = Coordinate::new(x, y, z);
+                    let count = nanobots.iter().filter(|n| n.is_in_range_of(c)).count();
+                    let candidate = (count, c.distance_from(origin));
+                    if best.is_none_or(|(b_count, b_distance)| count > b_count || (count == b_count && candidate.1 < b_distance)) {
+                        best = Some(candidate);
+                    }
+                }
+            }
+        }
+
+        let (position, count) = best_position(&NanobotIndex::new(nanobots));
+        assert_eq!((count, position.distance_from(origin)), best.unwrap());
+    }
+
+    #[test]
+    fn index_matches_linear_scans() {
+        let nanobots = random_nanobots(45, 200);
+        let index = NanobotIndex::new(nanobots.clone());
+
+        for c in random_nanobots(7, 50).iter().map(|n| n.pos) {
+            let covering: Vec<usize> = (0..nanobots.len()).filter(|&idx| nanobots[idx].is_in_range_of(c)).collect();
+            assert_eq!(index.covering(c), covering);
+
+            let within: Vec<usize> = (0..nanobots.len()).filter(|&idx| nanobots[idx].pos.distance_from(c) <= 10).collect();
+            assert_eq!(index.centred_within(c, 10), within);
+        }
+        for nanobot in &nanobots {
+            let overlapping: Vec<usize> = (0..nanobots.len())
+                .filter(|&idx| nanobots[idx].distance_from(nanobot) <= nanobots[idx].radius + nanobot.radius)
+                .collect();
+            assert_eq!(index.overlapping(nanobot), overlapping);
+        }
+    }
+
+    #[test]
+    fn max_clique_tests() {
+        let nanobots: Vec<Nanobot> = [
+            "pos=<10,12,12>, r=2",
+            "pos=<12,14,12>, r=2",
+            "pos=<16,12,12>, r=4",
+            "pos=<14,14,14>, r=6",
+            "pos=<50,50,50>, r=200",
+            "pos=<10,10,10>, r=5",
+        ].iter().map(|l| l.parse().unwrap()).collect();
+        let index = NanobotIndex::new(nanobots);
+        assert_eq!(index.max_clique(), vec![0, 1, 2, 3, 4]);
+
+        let nanobots = random_nanobots(23, 30);
+        let index = NanobotIndex::new(nanobots.clone());
+        let clique = index.max_clique();
+        assert!(clique.iter().all(|&a| clique.iter().all(|&b| index.overlapping(&nanobots[a]).contains(&b))));
+        assert!(clique.len() >= best_position(&index).1);
+    }
+}
@@ -0,0 +1,392 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+use std::str::FromStr;
+
+use regex::Regex;
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Side {
+    ImmuneSystem,
+    Infection
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Side::ImmuneSystem => write!(f, "Immune System"),
+            Side::Infection => write!(f, "Infection"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Group {
+    side: Side,
+    units: usize,
+    hit_points: usize,
+    attack_damage: usize,
+    attack_type: String,
+    initiative: usize,
+    weaknesses: Vec<String>,
+    immunities: Vec<String>
+}
+
+impl Group {
+    fn effective_power(&self) -> usize {
+        self.units * self.attack_damage
+    }
+
+    // Damage this group would deal to `defender`, before rounding down to whole units
+    fn damage_to(&self, defender: &Group) -> usize {
+        if defender.immunities.contains(&self.attack_type) {
+            0
+        } else if defender.weaknesses.contains(&self.attack_type) {
+            2 * self.effective_power()
+        } else {
+            self.effective_power()
+        }
+    }
+}
+
+// Groups are parsed as immune system groups; the army heading they appear under sets their side
+impl FromStr for Group {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self> {
+        lazy_static! {
+            static ref GROUP_RE: Regex = Regex::new(
+                r"^(?P<units>[0-9]+) units each with (?P<hp>[0-9]+) hit points (?:\((?P<modifiers>[^)]*)\) )?with an attack that does (?P<damage>[0-9]+) (?P<type>[a-z]+) damage at initiative (?P<initiative>[0-9]+)$"
+            ).unwrap();
+        }
+
+        let caps = match GROUP_RE.captures(s) {
+            Some(caps) => caps,
+            None => return err!("Cannot parse group: {}", s),
+        };
+
+        let mut weaknesses: Vec<String> = Vec::new();
+        let mut immunities: Vec<String> = Vec::new();
+        if let Some(modifiers) = caps.name("modifiers") {
+            for modifier in modifiers.as_str().split("; ") {
+                if let Some(types) = modifier.strip_prefix("weak to ") {
+                    weaknesses.extend(types.split(", ").map(|t| t.to_string()));
+                } else if let Some(types) = modifier.strip_prefix("immune to ") {
+                    immunities.extend(types.split(", ").map(|t| t.to_string()));
+                } else {
+                    return err!("Cannot parse weaknesses or immunities: {}", modifier);
+                }
+            }
+        }
+
+        Ok(Group {
+            side: Side::ImmuneSystem,
+            units: caps["units"].parse()?,
+            hit_points: caps["hp"].parse()?,
+            attack_damage: caps["damage"].parse()?,
+            attack_type: caps["type"].to_string(),
+            initiative: caps["initiative"].parse()?,
+            weaknesses,
+            immunities
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Outcome {
+    Victory { winner: Side, units_left: usize },
+    // A round passed in which no units died, so nothing will ever change
+    Stalemate
+}
+
+#[derive(Debug, Clone)]
+struct Battle {
+    groups: Vec<Group>
+}
+
+impl FromStr for Battle {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut groups: Vec<Group> = Vec::new();
+        let mut side: Option<Side> = None;
+        for (line_idx, line) in s.lines().map(|l| l.trim()).enumerate() {
+            match line {
+                "" => continue,
+                "Immune System:" => side = Some(Side::ImmuneSystem),
+                "Infection:" => side = Some(Side::Infection),
+                _ => {
+                    let mut group: Group = line.parse().map_err(|e| format!("Line {}: {}", line_idx + 1, e))?;
+                    group.side = match side {
+                        Some(side) => side,
+                        None => return err!("Line {}: group comes before any army heading", line_idx + 1),
+                    };
+                    groups.push(group);
+                },
+            }
+        }
+
+        Ok(Battle { groups })
+    }
+}
+
+impl Battle {
+    fn boost_immune_system(&mut self, boost: usize) {
+        for group in self.groups.iter_mut().filter(|g| g.side == Side::ImmuneSystem) {
+            group.attack_damage += boost;
+        }
+    }
+
+    // `targets[i]` is the group that group `i` will attack this round, if any
+    fn select_targets(&self) -> Vec<Option<usize>> {
+        let mut choosing_order: Vec<usize> = (0..self.groups.len()).collect();
+        choosing_order.sort_by_key(|&idx| {
+            let group = &self.groups[idx];
+            cmp::Reverse((group.effective_power(), group.initiative))
+        });
+
+        let mut targets: Vec<Option<usize>> = vec![None; self.groups.len()];
+        let mut targeted: Vec<bool> = vec![false; self.groups.len()];
+        for attacker_idx in choosing_order {
+            let attacker = &self.groups[attacker_idx];
+            let target = (0..self.groups.len())
+                .filter(|&idx| !targeted[idx] && self.groups[idx].side != attacker.side)
+                .map(|idx| {
+                    let defender = &self.groups[idx];
+                    ((attacker.damage_to(defender), defender.effective_power(), defender.initiative), idx)
+                })
+                .filter(|&((damage, _, _), _)| damage > 0)
+                .max();
+
+            if let Some((_, idx)) = target {
+                targets[attacker_idx] = Some(idx);
+                targeted[idx] = true;
+            }
+        }
+        targets
+    }
+
+    // Fights one round and returns how many units died
+    fn fight_round(&mut self) -> usize {
+        let targets = self.select_targets();
+
+        let mut attacking_order: Vec<usize> = (0..self.groups.len()).collect();
+        attacking_order.sort_by_key(|&idx| cmp::Reverse(self.groups[idx].initiative));
+
+        let mut units_killed = 0;
+        for attacker_idx in attacking_order {
+            let defender_idx = match targets[attacker_idx] {
+                Some(idx) if self.groups[attacker_idx].units > 0 => idx,
+                _ => continue,
+            };
+
+            let damage = self.groups[attacker_idx].damage_to(&self.groups[defender_idx]);
+            let defender = &mut self.groups[defender_idx];
+            let killed = cmp::min(damage / defender.hit_points, defender.units);
+            defender.units -= killed;
+            units_killed += killed;
+        }
+
+        self.groups.retain(|g| g.units > 0);
+        units_killed
+    }
+
+    fn winner(&self) -> Option<Side> {
+        let first_side = self.groups.first()?.side;
+        if self.groups.iter().all(|g| g.side == first_side) {
+            Some(first_side)
+        } else {
+            None
+        }
+    }
+
+    fn fight(&mut self) -> Outcome {
+        loop {
+            if let Some(winner) = self.winner() {
+                return Outcome::Victory { winner, units_left: self.groups.iter().map(|g| g.units).sum() };
+            }
+            if self.fight_round() == 0 {
+                return Outcome::Stalemate;
+            }
+        }
+    }
+}
+
+fn immune_system_wins(battle: &Battle, boost: usize) -> Option<usize> {
+    let mut boosted = battle.clone();
+    boosted.boost_immune_system(boost);
+    match boosted.fight() {
+        Outcome::Victory { winner: Side::ImmuneSystem, units_left } => Some(units_left),
+        _ => None,
+    }
+}
+
+// The smallest boost with which the immune system wins, and how many units it has left. Boosts
+// are tried in doubling steps until one wins, then the bracket below it is scanned one at a time.
+// A stalemate can appear between two losing boosts, so losses can't be bisected, but a bigger
+// boost doesn't turn a win back into a loss.
+fn smallest_winning_boost(battle: &Battle) -> Result<(usize, usize)> {
+    let infection = battle.groups.iter().filter(|g| g.side == Side::Infection);
+    let most_hit_points = infection.clone().map(|g| g.hit_points).max().ok_or("No infection groups!")?;
+    let most_units = infection.map(|g| g.units).max().unwrap_or_default();
+    let fewest_units = battle.groups
+        .iter()
+        .filter(|g| g.side == Side::ImmuneSystem)
+        .map(|g| g.units)
+        .min()
+        .ok_or("No immune system groups!")?;
+
+    // With this boost any immune system group wipes out any infection group it attacks in one go,
+    // so a bigger boost can't help
+    let most_useful_boost = (most_hit_points * most_units).div_ceil(fewest_units);
+
+    let mut losing_boost = 0;
+    let mut boost = 0;
+    let units_left = loop {
+        if let Some(units_left) = immune_system_wins(battle, boost) {
+            break units_left;
+        }
+        if boost >= most_useful_boost {
+            return err!("The immune system loses even with a boost of {}", boost);
+        }
+        losing_boost = boost;
+        boost = cmp::min(cmp::max(2 * boost, 1), most_useful_boost);
+    };
+
+    let smaller_win = (losing_boost + 1..boost)
+        .filter_map(|boost| immune_system_wins(battle, boost).map(|units_left| (boost, units_left)))
+        .next();
+    Ok(smaller_win.unwrap_or((boost, units_left)))
+}
+
+fn read_battle(fname: String) -> String {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+    f_contents
+}
+
+pub fn q1(fname: String) -> usize {
+    _q1(read_battle(fname)).unwrap()
+}
+
+fn _q1(battle_description: String) -> Result<usize> {
+    let mut battle: Battle = battle_description.parse()?;
+
+    match battle.fight() {
+        Outcome::Victory { units_left, .. } => Ok(units_left),
+        Outcome::Stalemate => err!("The battle ended in a stalemate"),
+    }
+}
+
+pub fn q2(fname: String) -> usize {
+    _q2(read_battle(fname)).unwrap()
+}
+
+fn _q2(battle_description: String) -> Result<usize> {
+    let battle: Battle = battle_description.parse()?;
+
+    let (_, units_left) = smallest_winning_boost(&battle)?;
+    Ok(units_left)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        "
+        Immune System:
+        17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
+        989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
+
+        Infection:
+        801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
+        4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4
+        ".to_string()
+    }
+
+    #[test]
+    fn day24_q1() {
+        assert_eq!(_q1(example()).unwrap(), 5216);
+    }
+
+    #[test]
+    fn day24_q2() {
+        let battle: Battle = example().parse().unwrap();
+        assert_eq!(smallest_winning_boost(&battle).unwrap(), (1570, 51));
+        assert_eq!(_q2(example()).unwrap(), 51);
+    }
+
+    #[test]
+    fn parse_tests() {
+        let battle: Battle = example().parse().unwrap();
+        assert_eq!(
+            battle.groups[1],
+            Group {
+                side: Side::ImmuneSystem,
+                units: 989,
+                hit_points: 1274,
+                attack_damage: 25,
+                attack_type: "slashing".to_string(),
+                initiative: 3,
+                weaknesses: vec!["bludgeoning".to_string(), "slashing".to_string()],
+                immunities: vec!["fire".to_string()]
+            }
+        );
+        assert_eq!(battle.groups[2].side, Side::Infection);
+
+        let group: Group = "10 units each with 20 hit points with an attack that does 3 cold damage at initiative 7".parse().unwrap();
+        assert!(group.weaknesses.is_empty() && group.immunities.is_empty());
+
+        assert!("1 units each with 2 hit points (fond of fire) with an attack that does 3 cold damage at initiative 4".parse::<Group>().is_err());
+        assert!("1 units each with 2 hit points with an attack that does 3 cold damage at initiative 4".parse::<Battle>().is_err());
+    }
+
+    #[test]
+    fn stalemate_test() {
+        // Each side is immune to the other's attacks, so nobody can be targeted
+        let mut battle: Battle = "
+            Immune System:
+            10 units each with 10 hit points (immune to fire) with an attack that does 5 cold damage at initiative 2
+
+            Infection:
+            10 units each with 10 hit points (immune to cold) with an attack that does 5 fire damage at initiative 1
+        ".parse().unwrap();
+        assert_eq!(battle.fight(), Outcome::Stalemate);
+
+        // Attacks land but are too weak to kill a whole unit
+        let mut battle: Battle = "
+            Immune System:
+            10 units each with 100 hit points with an attack that does 5 cold damage at initiative 2
+
+            Infection:
+            10 units each with 100 hit points with an attack that does 5 fire damage at initiative 1
+        ".parse().unwrap();
+        assert_eq!(battle.fight(), Outcome::Stalemate);
+    }
+
+    #[test]
+    fn unwinnable_battle_test() {
+        // No boost gets past the infection's immunity, and the search stops at a bounded boost
+        let battle: Battle = "
+            Immune System:
+            1 units each with 1 hit points with an attack that does 1 fire damage at initiative 2
+
+            Infection:
+            1000 units each with 1000 hit points (immune to fire) with an attack that does 5 cold damage at initiative 1
+        ".parse().unwrap();
+        let message = smallest_winning_boost(&battle).unwrap_err().to_string();
+        assert_eq!(message, "The immune system loses even with a boost of 1000000");
+    }
+}
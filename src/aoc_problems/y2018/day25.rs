@@ -0,0 +1,89 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::prelude::*;
+use std::result;
+
+use aoc_problems::clustering::{count_manhattan_clusters, parse_points};
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+const DIMENSION: usize = 4;
+const CONSTELLATION_DISTANCE: u64 = 3;
+
+pub fn q1(fname: String) -> usize {
+    let mut f = File::open(fname).expect("File not found");
+    let mut f_contents = String::new();
+
+    f.read_to_string(&mut f_contents).expect("Couldn't find file");
+
+    _q1(f_contents).unwrap()
+}
+
+fn _q1(point_list: String) -> Result<usize> {
+    let points = parse_points(&point_list, DIMENSION)?;
+
+    Ok(count_manhattan_clusters(&points, CONSTELLATION_DISTANCE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day25_q1() {
+        assert_eq!(
+            _q1("
+                0,0,0,0
+                3,0,0,0
+                0,3,0,0
+                0,0,3,0
+                0,0,0,3
+                0,0,0,6
+                9,0,0,0
+                12,0,0,0
+            ".to_string()).unwrap(), 2
+        );
+        assert_eq!(
+            _q1("
+                -1,2,2,0
+                0,0,2,-2
+                0,0,0,-2
+                -1,2,0,0
+                -2,-2,-2,2
+                3,0,2,-1
+                -1,3,2,2
+                -1,0,-1,0
+                0,2,1,-2
+                3,0,0,0
+            ".to_string()).unwrap(), 4
+        );
+        assert_eq!(
+            _q1("
+                1,-1,0,1
+                2,0,-1,0
+                3,2,-1,0
+                0,0,3,1
+                0,0,-1,-1
+                2,3,-2,0
+                -2,2,0,0
+                2,-2,0,-1
+                1,-1,0,-1
+                3,2,0,2
+            ".to_string()).unwrap(), 3
+        );
+        assert_eq!(
+            _q1("
+                1,-1,-1,-2
+                -2,-2,0,1
+                0,2,1,3
+                -2,3,-2,1
+                0,2,3,-2
+                -1,-1,1,-2
+                0,-2,-1,0
+                -2,2,3,-1
+                1,2,2,0
+                -1,-2,0,-2
+            ".to_string()).unwrap(), 8
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use std::error::Error;
+use std::result;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+pub type Registers = [usize; 6];
+
+// How an instruction uses its first two numbers
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Operand {
+    Register,
+    Immediate,
+    Unused
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Operation {
+    Add,
+    Multiply,
+    BitAnd,
+    BitOr,
+    Set,
+    GreaterThan,
+    Equal
+}
+
+impl Operation {
+    fn apply(self, a: usize, b: usize) -> usize {
+        match self {
+            Operation::Add => a.wrapping_add(b),
+            Operation::Multiply => a.wrapping_mul(b),
+            Operation::BitAnd => a & b,
+            Operation::BitOr => a | b,
+            Operation::Set => a,
+            Operation::GreaterThan => (a > b) as usize,
+            Operation::Equal => (a == b) as usize,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Opcode {
+    pub name: &'static str,
+    pub operation: Operation,
+    pub a: Operand,
+    pub b: Operand
+}
+
+macro_rules! opcode {
+    ($name:expr, $operation:ident, $a:ident, $b:ident) => {
+        Opcode { name: $name, operation: Operation::$operation, a: Operand::$a, b: Operand::$b }
+    }
+}
+
+pub const OPCODES: [Opcode; 16] = [
+    opcode!("addr", Add, Register, Register),
+    opcode!("addi", Add, Register, Immediate),
+    opcode!("mulr", Multiply, Register, Register),
+    opcode!("muli", Multiply, Register, Immediate),
+    opcode!("banr", BitAnd, Register, Register),
+    opcode!("bani", BitAnd, Register, Immediate),
+    opcode!("borr", BitOr, Register, Register),
+    opcode!("bori", BitOr, Register, Immediate),
+    opcode!("setr", Set, Register, Unused),
+    opcode!("seti", Set, Immediate, Unused),
+    opcode!("gtir", GreaterThan, Immediate, Register),
+    opcode!("gtri", GreaterThan, Register, Immediate),
+    opcode!("gtrr", GreaterThan, Register, Register),
+    opcode!("eqir", Equal, Immediate, Register),
+    opcode!("eqri", Equal, Register, Immediate),
+    opcode!("eqrr", Equal, Register, Register),
+];
+
+pub fn opcode_named(name: &str) -> Option<&'static Opcode> {
+    OPCODES.iter().find(|opcode| opcode.name == name)
+}
+
+fn value_of(operand: Operand, value: usize, registers: &Registers) -> usize {
+    match operand {
+        Operand::Register => registers[value],
+        Operand::Immediate => value,
+        Operand::Unused => 0,
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Instruction {
+    pub opcode: &'static Opcode,
+    pub a: usize,
+    pub b: usize,
+    pub c: usize
+}
+
+impl Instruction {
+    fn parse(line: &str) -> Result<Instruction> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 4 {
+            return err!("Expected an opcode and three numbers, found {:?}", line);
+        }
+
+        let opcode = match opcode_named(fields[0]) {
+            Some(opcode) => opcode,
+            None => return err!("Unknown opcode {:?}", fields[0]),
+        };
+        let instruction = Instruction { opcode, a: fields[1].parse()?, b: fields[2].parse()?, c: fields[3].parse()? };
+
+        let out_of_range = [(opcode.a, instruction.a), (opcode.b, instruction.b), (Operand::Register, instruction.c)]
+            .iter()
+            .any(|&(operand, value)| operand == Operand::Register && value >= 6);
+        if out_of_range {
+            return err!("Register out of range in {:?}", line);
+        }
+        Ok(instruction)
+    }
+
+    pub fn reads_register(&self, register: usize) -> bool {
+        (self.opcode.a == Operand::Register && self.a == register) || (self.opcode.b == Operand::Register && self.b == register)
+    }
+
+    // For an equality test involving `register`, whatever `register` is being compared against
+    pub fn compared_with(&self, register: usize, registers: &Registers) -> Option<usize> {
+        if self.opcode.operation != Operation::Equal {
+            return None;
+        }
+        if self.opcode.a == Operand::Register && self.a == register {
+            Some(value_of(self.opcode.b, self.b, registers))
+        } else if self.opcode.b == Operand::Register && self.b == register {
+            Some(value_of(self.opcode.a, self.a, registers))
+        } else {
+            None
+        }
+    }
+
+    pub fn execute(&self, registers: &mut Registers) {
+        let a = value_of(self.opcode.a, self.a, registers);
+        let b = value_of(self.opcode.b, self.b, registers);
+        registers[self.c] = self.opcode.operation.apply(a, b);
+    }
+}
+
+pub struct Program {
+    pub ip_register: usize,
+    pub instructions: Vec<Instruction>
+}
+
+impl Program {
+    // A `#ip <register>` line followed by one instruction per line
+    pub fn new(instruction_list: &[String]) -> Result<Program> {
+        let ip_line = match instruction_list.first() {
+            Some(line) if line.trim().starts_with("#ip ") => line.trim(),
+            _ => return err!("First line does not match instruction_ptr format"),
+        };
+        let ip_register: usize = ip_line["#ip ".len()..].trim().parse()?;
+        if ip_register >= 6 {
+            return err!("Cannot bind the instruction pointer to register {}", ip_register);
+        }
+
+        let instructions: Vec<Instruction> = instruction_list[1..]
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| !s.trim().is_empty())
+            .map(|(idx, s)| Instruction::parse(s.trim()).map_err(|e| format!("Line {}: {}", idx + 2, e).into()))
+            .collect::<Result<_>>()?;
+
+        Ok(Program { ip_register, instructions })
+    }
+
+    // Runs the instruction at `ip`, returning where to go next
+    pub fn step(&self, ip: usize, registers: &mut Registers) -> usize {
+        registers[self.ip_register] = ip;
+        self.instructions[ip].execute(registers);
+        registers[self.ip_register].wrapping_add(1)
+    }
+
+    // Runs from `ip` until the program halts, giving `None`, or is about to run the instruction at
+    // `stop_at`, giving `Some(stop_at)`. `stop_at` itself is not checked before the first step.
+    pub fn run_until(&self, mut ip: usize, registers: &mut Registers, stop_at: usize) -> Option<usize> {
+        while ip < self.instructions.len() {
+            ip = self.step(ip, registers);
+            if ip == stop_at {
+                return Some(ip);
+            }
+        }
+        None
+    }
+
+    // Runs from the start until the program halts
+    pub fn run(&self, registers: &mut Registers) {
+        if !self.instructions.is_empty() {
+            self.run_until(0, registers, usize::MAX);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opcode_tests() {
+        let mut registers: Registers = [3, 2, 1, 1, 0, 0];
+        Instruction::parse("mulr 2 1 2").unwrap().execute(&mut registers);
+        assert_eq!(registers, [3, 2, 2, 1, 0, 0]);
+        Instruction::parse("gtir 3 0 4").unwrap().execute(&mut registers);
+        assert_eq!(registers, [3, 2, 2, 1, 0, 0]);
+        Instruction::parse("eqir 3 0 4").unwrap().execute(&mut registers);
+        assert_eq!(registers, [3, 2, 2, 1, 1, 0]);
+
+        let seti = Instruction::parse("seti 7 0 5").unwrap();
+        assert!(!seti.reads_register(0) && !seti.reads_register(7));
+        let eqri = Instruction::parse("eqri 3 72 3").unwrap();
+        assert!(eqri.reads_register(3));
+        assert_eq!(eqri.compared_with(3, &registers), Some(72));
+
+        assert!(Instruction::parse("addr 1 6 0").is_err());
+        assert!(Instruction::parse("addr 1 5 0").is_ok());
+        assert!(Instruction::parse("noop 1 2 0").is_err());
+    }
+
+    #[test]
+    fn run_until_tests() {
+        // Counts register 1 up to 3, then halts
+        let counting = Program::new(&[
+            "#ip 5".to_string(),
+            "seti 0 0 0".to_string(),
+            "addi 1 1 1".to_string(),
+            "gtri 1 2 2".to_string(),
+            "addr 2 5 5".to_string(),
+            "seti 0 0 5".to_string(),
+        ]).unwrap();
+
+        let mut registers: Registers = [0; 6];
+        assert_eq!(counting.run_until(0, &mut registers, 4), Some(4));
+        assert_eq!(registers[1], 1);
+        assert_eq!(counting.run_until(4, &mut registers, 4), Some(4));
+        assert_eq!(registers[1], 2);
+        assert_eq!(counting.run_until(4, &mut registers, 4), None);
+        assert_eq!(registers[1], 3);
+
+        let mut registers: Registers = [0; 6];
+        counting.run(&mut registers);
+        assert_eq!(registers[1], 3);
+    }
+}
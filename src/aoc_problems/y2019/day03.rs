@@ -2,12 +2,11 @@ use std::cmp;
 use std::error::Error;
 use std::fmt;
 use std::fs::File;
-use std::io;
 use std::io::prelude::*;
 use std::ops::{Add, Sub, AddAssign};
 use std::result;
 
-use std::collections::{BTreeMap, HashSet, HashMap};
+use std::collections::{BTreeMap, HashMap};
 
 type Result<T> = result::Result<T, Box<dyn Error>>;
 
@@ -15,17 +14,6 @@ macro_rules! err {
     ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
 }
 
-fn pause() {
-    let mut stdin = io::stdin();
-    let mut stdout = io::stdout();
-
-    // We want the cursor to stay at the end of the line, so we print without a newline and flush manually.
-    write!(stdout, "Press any key to continue...").unwrap();
-    stdout.flush().unwrap();
-
-    // Read a single byte and discard
-    let _ = stdin.read(&mut [0u8]).unwrap();
-}
 
 #[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
 struct Coordinate {
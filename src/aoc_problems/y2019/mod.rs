@@ -0,0 +1,2 @@
+pub mod day02;
+pub mod day03;
@@ -0,0 +1,355 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::result;
+use std::str;
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+}
+
+// Overrides where inputs are looked for
+pub const INPUTS_ROOT_VAR: &str = "AOC_INPUTS";
+
+// Kept in each year's directory, one `<file name> <checksum>` line per imported input
+pub const CHECKSUM_FILE: &str = "checksums.txt";
+
+// 64-bit FNV-1a, which is stable across Rust versions unlike the std hashers
+pub fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Import {
+    Copied,
+    // The file was already in place, and its checksum is now recorded. `replaced` is the checksum
+    // recorded before, if the file has been edited since.
+    Recorded { replaced: Option<u64>, checksum: u64 },
+    Unchanged
+}
+
+// The first year of Advent of Code
+pub const FIRST_YEAR: u32 = 2015;
+
+// Finds puzzle inputs under a root directory. Imported inputs live at `<year>/07.txt`, or
+// `<year>/16_2.txt` for a second file used by part 2. Older inputs were saved loose in the root as
+// `day7.txt`, `day1_1.txt` or `day02.txt`, with no record of their year.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputResolver {
+    root: PathBuf,
+    // Whether `root` is the fallback baked in at compile time rather than one asked for
+    built_in_root: bool
+}
+
+impl InputResolver {
+    pub fn new<P: AsRef<Path>>(root: P) -> InputResolver {
+        InputResolver { root: root.as_ref().to_path_buf(), built_in_root: false }
+    }
+
+    // `$AOC_INPUTS` if set, otherwise the `inputs` directory of the crate as it was when compiled,
+    // so the current working directory doesn't matter
+    pub fn from_env() -> InputResolver {
+        match env::var_os(INPUTS_ROOT_VAR) {
+            Some(root) => InputResolver::new(root),
+            None => InputResolver {
+                built_in_root: true,
+                ..InputResolver::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"))
+            },
+        }
+    }
+
+    fn store_name(day: u32, part: Option<u32>) -> String {
+        match part {
+            Some(part) => format!("{:02}_{}.txt", day, part),
+            None => format!("{:02}.txt", day),
+        }
+    }
+
+    pub fn store_path(&self, year: u32, day: u32, part: Option<u32>) -> PathBuf {
+        self.root.join(year.to_string()).join(InputResolver::store_name(day, part))
+    }
+
+    // Paths an input could be at, grouped by how specific they are, most specific first. A
+    // part-specific file wins over the day's shared input, and the store wins over loose files. The
+    // loose names within a group are different conventions for the same input.
+    fn candidate_groups(&self, year: u32, day: u32, part: Option<u32>) -> Vec<Vec<PathBuf>> {
+        let mut groups: Vec<Vec<PathBuf>> = Vec::new();
+        if part.is_some() {
+            groups.push(vec![self.store_path(year, day, part)]);
+        }
+        groups.push(vec![self.store_path(year, day, None)]);
+
+        let part_name = part.map(|part| format!("day{}_{}.txt", day, part));
+        let mut shared_names = vec![format!("day{}.txt", day), format!("day{}_1.txt", day)];
+        if day < 10 {
+            shared_names.push(format!("day{:02}.txt", day));
+        }
+        if let Some(part_name) = part_name {
+            shared_names.retain(|name| *name != part_name);
+            groups.push(vec![self.root.join(part_name)]);
+        }
+        groups.push(shared_names.into_iter().map(|name| self.root.join(name)).collect());
+
+        groups
+    }
+
+    pub fn candidates(&self, year: u32, day: u32, part: Option<u32>) -> Vec<PathBuf> {
+        self.candidate_groups(year, day, part).concat()
+    }
+
+    fn first_existing(&self, year: u32, day: u32, part: Option<u32>) -> Result<PathBuf> {
+        let description = match part {
+            Some(part) => format!("{} day {} part {}", year, day, part),
+            None => format!("{} day {}", year, day),
+        };
+
+        for group in self.candidate_groups(year, day, part) {
+            let existing: Vec<&PathBuf> = group.iter().filter(|path| path.is_file()).collect();
+            match existing.as_slice() {
+                [] => continue,
+                [path] => {
+                    self.verify(year, path)?;
+                    return Ok(path.to_path_buf());
+                },
+                _ => {
+                    let found: Vec<String> = existing.iter().map(|path| format!("  {}", path.display())).collect();
+                    return err!("More than one input found for {}; remove all but one of:\n{}", description, found.join("\n"));
+                },
+            }
+        }
+
+        let tried: Vec<String> = self.candidates(year, day, part).iter().map(|path| format!("  {}", path.display())).collect();
+        let root_note = if self.built_in_root {
+            format!(
+                "\n{} is the inputs directory of the crate when it was compiled; set ${} to look elsewhere",
+                self.root.display(), INPUTS_ROOT_VAR
+            )
+        } else {
+            String::new()
+        };
+        err!("No input found for {}; tried:\n{}{}", description, tried.join("\n"), root_note)
+    }
+
+    pub fn resolve(&self, year: u32, day: u32) -> Result<PathBuf> {
+        self.first_existing(year, day, None)
+    }
+
+    pub fn resolve_part(&self, year: u32, day: u32, part: u32) -> Result<PathBuf> {
+        self.first_existing(year, day, Some(part))
+    }
+
+    fn checksum_path(&self, year: u32) -> PathBuf {
+        self.root.join(year.to_string()).join(CHECKSUM_FILE)
+    }
+
+    fn read_checksums(&self, year: u32) -> Result<BTreeMap<String, u64>> {
+        let path = self.checksum_path(year);
+        if !path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let mut checksums: BTreeMap<String, u64> = BTreeMap::new();
+        for (line_idx, line) in fs::read_to_string(&path)?.lines().enumerate() {
+            let mut fields = line.split_whitespace();
+            match (fields.next(), fields.next().map(|c| u64::from_str_radix(c, 16)), fields.next()) {
+                (Some(name), Some(Ok(sum)), None) => { checksums.insert(name.to_string(), sum); },
+                _ => return err!("{} line {}: cannot read checksum from {:?}", path.display(), line_idx + 1, line),
+            }
+        }
+        Ok(checksums)
+    }
+
+    fn write_checksums(&self, year: u32, checksums: &BTreeMap<String, u64>) -> Result<()> {
+        let lines: Vec<String> = checksums.iter().map(|(name, sum)| format!("{} {:016x}\n", name, sum)).collect();
+        fs::write(self.checksum_path(year), lines.concat())?;
+        Ok(())
+    }
+
+    // Fails if `path` is in the year's store and no longer matches the checksum taken when it was
+    // imported
+    fn verify(&self, year: u32, path: &Path) -> Result<()> {
+        if path.parent() != Some(self.root.join(year.to_string()).as_path()) {
+            return Ok(());
+        }
+        let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+
+        if let Some(&expected) = self.read_checksums(year)?.get(name) {
+            let actual = checksum(&fs::read(path)?);
+            if actual != expected {
+                return err!(
+                    "{} has changed since it was imported (checksum {:016x}, recorded {:016x}); import it again if the edit was intended",
+                    path.display(), actual, expected
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // Copies `source` into the store, or records the checksum of an input already in place. An
+    // input that differs from one already in the store is refused rather than overwritten.
+    pub fn import(&self, year: u32, day: u32, part: Option<u32>, source: &Path) -> Result<Import> {
+        if year < FIRST_YEAR {
+            return err!("There is no {} puzzle; Advent of Code began in {}", year, FIRST_YEAR);
+        }
+        if !(1..=25).contains(&day) {
+            return err!("There is no day {}", day);
+        }
+
+        let contents = match fs::read(source) {
+            Ok(contents) => contents,
+            Err(e) => return err!("Cannot read {}: {}", source.display(), e),
+        };
+        if str::from_utf8(&contents).is_err() {
+            return err!("{} is not a text file", source.display());
+        }
+        if contents.iter().all(|b| b.is_ascii_whitespace()) {
+            return err!("{} is empty", source.display());
+        }
+
+        let name = InputResolver::store_name(day, part);
+        let sum = checksum(&contents);
+        let mut checksums = self.read_checksums(year)?;
+
+        let destination = self.store_path(year, day, part);
+        let in_place = destination.exists() && fs::canonicalize(&destination)? == fs::canonicalize(source)?;
+        let outcome = if in_place {
+            let replaced = checksums.get(&name).cloned().filter(|&recorded| recorded != sum);
+            Import::Recorded { replaced, checksum: sum }
+        } else if destination.exists() {
+            if fs::read(&destination)? != contents {
+                return err!("{} already holds a different input; remove it first to replace it", destination.display());
+            }
+            Import::Unchanged
+        } else {
+            fs::create_dir_all(destination.parent().unwrap())?;
+            fs::write(&destination, &contents)?;
+            Import::Copied
+        };
+
+        checksums.insert(name, sum);
+        self.write_checksums(year, &checksums)?;
+
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_inputs_{}_{}", name, ::std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn resolve_tests() {
+        let root = scratch_dir("resolve");
+        fs::create_dir_all(root.join("2018")).unwrap();
+        for name in &["day1_1.txt", "day7.txt", "day7_test.txt", "day12.txt", "day16.txt", "day16_2.txt", "2018/07.txt"] {
+            fs::write(root.join(name), "1").unwrap();
+        }
+        let resolver = InputResolver::new(&root);
+
+        assert_eq!(resolver.resolve(2018, 1).unwrap(), root.join("day1_1.txt"));
+        assert_eq!(resolver.resolve_part(2018, 1, 1).unwrap(), root.join("day1_1.txt"));
+        assert_eq!(resolver.resolve_part(2018, 1, 2).unwrap(), root.join("day1_1.txt"));
+        assert_eq!(resolver.resolve(2018, 7).unwrap(), root.join("2018/07.txt"));
+        assert_eq!(resolver.resolve(2019, 7).unwrap(), root.join("day7.txt"));
+        assert_eq!(resolver.resolve_part(2018, 12, 2).unwrap(), root.join("day12.txt"));
+        assert_eq!(resolver.resolve_part(2018, 16, 1).unwrap(), root.join("day16.txt"));
+        assert_eq!(resolver.resolve_part(2018, 16, 2).unwrap(), root.join("day16_2.txt"));
+
+        let message = resolver.resolve_part(2018, 3, 2).unwrap_err().to_string();
+        assert!(message.starts_with("No input found for 2018 day 3 part 2; tried:"));
+        for name in &["2018/03_2.txt", "2018/03.txt", "day3_2.txt", "day3.txt", "day3_1.txt", "day03.txt"] {
+            assert!(message.contains(&root.join(name).display().to_string()));
+        }
+        assert!(!message.contains("when it was compiled"));
+        let built_in = InputResolver { built_in_root: true, ..resolver.clone() };
+        let message = built_in.resolve_part(2018, 3, 2).unwrap_err().to_string();
+        assert!(message.ends_with(&format!(
+            "{} is the inputs directory of the crate when it was compiled; set $AOC_INPUTS to look elsewhere",
+            root.display()
+        )));
+
+        // Two conventions for the same input are refused rather than one being picked
+        for name in &["day2_1.txt", "day02.txt", "day4.txt", "day4_1.txt"] {
+            fs::write(root.join(name), "1").unwrap();
+        }
+        let message = resolver.resolve(2019, 2).unwrap_err().to_string();
+        assert!(message.starts_with("More than one input found for 2019 day 2; remove all but one of:"));
+        assert!(message.contains(&root.join("day2_1.txt").display().to_string()));
+        assert!(message.contains(&root.join("day02.txt").display().to_string()));
+        assert!(resolver.resolve_part(2019, 2, 2).is_err());
+        assert!(resolver.resolve(2018, 4).is_err());
+        assert_eq!(resolver.resolve_part(2018, 4, 1).unwrap(), root.join("day4_1.txt"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn import_tests() {
+        let root = scratch_dir("import");
+        let resolver = InputResolver::new(root.join("inputs"));
+        let download = root.join("input.txt");
+        fs::write(&download, "1,2,3\n").unwrap();
+
+        assert_eq!(resolver.import(2019, 2, None, &download).unwrap(), Import::Copied);
+        let imported = resolver.resolve(2019, 2).unwrap();
+        assert_eq!(imported, root.join("inputs/2019/02.txt"));
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/2019").join(CHECKSUM_FILE)).unwrap(),
+            format!("02.txt {:016x}\n", checksum(b"1,2,3\n"))
+        );
+        assert_eq!(resolver.import(2019, 2, None, &download).unwrap(), Import::Unchanged);
+
+        fs::write(&download, "4,5,6\n").unwrap();
+        assert!(resolver.import(2019, 2, None, &download).is_err());
+        assert!(resolver.import(2019, 26, None, &download).is_err());
+        assert!(resolver.import(2014, 2, None, &download).unwrap_err().to_string().contains("began in 2015"));
+        fs::write(root.join("binary.txt"), [0xff, 0xfe, 0x31]).unwrap();
+        assert!(resolver.import(2019, 3, None, &root.join("binary.txt")).unwrap_err().to_string().contains("not a text file"));
+        fs::write(root.join("empty.txt"), "\n").unwrap();
+        assert!(resolver.import(2019, 3, None, &root.join("empty.txt")).is_err());
+
+        // An edit in place is caught until the file is imported again
+        fs::write(&imported, "1,2,4\n").unwrap();
+        assert!(resolver.resolve(2019, 2).unwrap_err().to_string().contains("has changed since it was imported"));
+        assert_eq!(
+            resolver.import(2019, 2, None, &imported).unwrap(),
+            Import::Recorded { replaced: Some(checksum(b"1,2,3\n")), checksum: checksum(b"1,2,4\n") }
+        );
+        assert_eq!(resolver.resolve(2019, 2).unwrap(), imported);
+        assert_eq!(
+            resolver.import(2019, 2, None, &imported).unwrap(),
+            Import::Recorded { replaced: None, checksum: checksum(b"1,2,4\n") }
+        );
+
+        assert_eq!(resolver.import(2018, 16, Some(2), &download).unwrap(), Import::Copied);
+        assert_eq!(resolver.resolve_part(2018, 16, 2).unwrap(), root.join("inputs/2018/16_2.txt"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn checked_in_inputs_match_their_checksums() {
+        let resolver = InputResolver::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"));
+        for year in &[2018, 2019] {
+            let checksums = resolver.read_checksums(*year).unwrap();
+            assert!(!checksums.is_empty());
+            for name in checksums.keys() {
+                resolver.verify(*year, &resolver.root.join(year.to_string()).join(name)).unwrap();
+            }
+        }
+
+        assert_eq!(resolver.resolve(2018, 2).unwrap(), resolver.root.join("2018/02.txt"));
+        assert_eq!(resolver.resolve(2019, 2).unwrap(), resolver.root.join("2019/02.txt"));
+        assert_eq!(resolver.resolve_part(2018, 16, 2).unwrap(), resolver.root.join("2018/16_2.txt"));
+    }
+}
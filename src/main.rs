@@ -4,14 +4,149 @@ extern crate lazy_static;
 extern crate regex;
 extern crate itertools;
 
+use std::env;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process;
 use std::time::Instant;
 
 mod aoc_problems;
+mod inputs;
+
+const USAGE: &str = "Usage: aoc_2018 [<year> <day> <part>]
+       aoc_2018 animate <year> <day> [--fps <frames per second>] [--frames <directory>] [<option> <value>]...
+       aoc_2018 import <year> <day> [<part>] <file>";
+
+const DEFAULT_FRAMES_PER_SECOND: f64 = 10.0;
+
+// Run when no puzzle is given
+const DEFAULT_PUZZLE: (u32, u32, u32) = (2019, 3, 2);
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn parse_number(arg: &str, what: &str) -> u32 {
+    arg.parse().unwrap_or_else(|_| usage_error(&format!("Cannot read {} from {:?}", what, arg)))
+}
+
+// Copies a downloaded input into the store, or records the checksum of one already there
+fn import(args: &[String]) {
+    let (year, day, part, source) = match args {
+        [year, day, source] => (year, day, None, source),
+        [year, day, part, source] => (year, day, Some(parse_number(part, "part")), source),
+        _ => usage_error("import needs a year, a day, optionally a part, and a file"),
+    };
+    let (year, day) = (parse_number(year, "year"), parse_number(day, "day"));
+
+    let resolver = inputs::InputResolver::from_env();
+    match resolver.import(year, day, part, Path::new(source)) {
+        Ok(inputs::Import::Recorded { replaced: Some(replaced), checksum }) => println!(
+            "Recorded {}, which has changed since it was imported (checksum was {:016x}, now {:016x})",
+            resolver.store_path(year, day, part).display(), replaced, checksum
+        ),
+        Ok(inputs::Import::Recorded { replaced: None, .. }) => {
+            println!("Recorded {}", resolver.store_path(year, day, part).display())
+        },
+        Ok(outcome) => println!("{:?} {}", outcome, resolver.store_path(year, day, part).display()),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        },
+    }
+}
+
+// The path of a resolved input, or the reason there isn't one
+fn input_path(resolved: Result<PathBuf, Box<dyn Error>>) -> String {
+    match resolved {
+        Ok(path) => path.display().to_string(),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        },
+    }
+}
+
+// Shows a puzzle's solution step by step, either in the terminal at `--fps` frames a second (0 for
+// as fast as possible) or as numbered text files in the `--frames` directory
+fn animate(args: &[String]) {
+    let (year, day, options) = match args {
+        [year, day, options @ ..] => (parse_number(year, "year"), parse_number(day, "day"), options),
+        _ => usage_error("animate needs a year and a day"),
+    };
+
+    let animation = aoc_problems::find_animation(year, day).unwrap_or_else(|| {
+        let listed: Vec<String> = aoc_problems::animations().iter().map(|a| format!("{} day {}", a.year, a.day)).collect();
+        eprintln!("No animation for {} day {}; there are: {}", year, day, listed.join(", "));
+        process::exit(1);
+    });
+
+    let mut frames_per_second = DEFAULT_FRAMES_PER_SECOND;
+    let mut frames_dir: Option<PathBuf> = None;
+    let mut puzzle_options: Vec<(String, String)> = Vec::new();
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        let value = options.next().unwrap_or_else(|| usage_error(&format!("{} needs a value", option)));
+        match option.as_str() {
+            "--fps" => {
+                frames_per_second = match value.parse::<f64>() {
+                    Ok(fps) if fps.is_finite() && fps >= 0.0 => fps,
+                    _ => usage_error(&format!("Cannot read frames per second from {:?}", value)),
+                };
+            },
+            "--frames" => frames_dir = Some(PathBuf::from(value)),
+            _ if animation.options.contains(&option.as_str()) => puzzle_options.push((option.clone(), value.clone())),
+            _ if animation.options.is_empty() => usage_error(&format!("Unknown option {:?}", option)),
+            _ => usage_error(&format!(
+                "Unknown option {:?}; {} day {} also takes {}", option, year, day, animation.options.join(", ")
+            )),
+        }
+    }
+    let target = match frames_dir {
+        Some(dir) => aoc_problems::animation::Target::Directory(dir),
+        None => aoc_problems::animation::Target::Terminal { frames_per_second },
+    };
+
+    let input = input_path(inputs::InputResolver::from_env().resolve(year, day));
+
+    println!("Answer: {}", (animation.run)(input, target, &puzzle_options));
+}
 
 fn main() {
-    let now = Instant::now();
-    let result = aoc_problems::day_03::q2("./inputs/day03.txt".to_string());
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(|a| a.as_str()) {
+        Some("import") => return import(&args[1..]),
+        Some("animate") => return animate(&args[1..]),
+        _ => {},
+    }
+
+    let (year, day, part) = match args.as_slice() {
+        [] => DEFAULT_PUZZLE,
+        [year, day, part] => (parse_number(year, "year"), parse_number(day, "day"), parse_number(part, "part")),
+        _ => usage_error("A puzzle is a year, a day and a part"),
+    };
+
+    let solution = aoc_problems::find_solution(year, day, part).unwrap_or_else(|| {
+        let listed: Vec<String> = aoc_problems::solutions()
+            .iter()
+            .filter(|s| s.year == year)
+            .map(|s| format!("{}.{}", s.day, s.part))
+            .collect();
+        eprintln!("No solution for {} day {} part {}; {} has: {}", year, day, part, year, listed.join(" "));
+        process::exit(1);
+    });
+    let resolver = inputs::InputResolver::from_env();
+    let input = input_path(resolver.resolve(year, day));
+
+    let (now, result) = match solution.run {
+        aoc_problems::Run::Input(run) => (Instant::now(), run(input)),
+        aoc_problems::Run::WithPartInput(run) => {
+            let part_input = input_path(resolver.resolve_part(year, day, part));
+            (Instant::now(), run(input, part_input))
+        },
+    };
     let elapsed = now.elapsed();
-    println!("Answer: {:?}", result);
+    println!("Answer: {}", result);
     println!("Elapsed time: {:?}", elapsed);
 }